@@ -6,11 +6,16 @@ use clap_complete::{Generator, Shell, generate};
 use clap_mangen::Man;
 
 #[path = "src/enums.rs"]
+#[allow(dead_code)]
 mod enums;
 
 #[path = "src/args.rs"]
 mod args;
 
+#[path = "src/rules.rs"]
+#[allow(dead_code)]
+mod rules;
+
 fn main() {
     let update_docs = cfg!(feature = "doc");
     let is_ci = env::var("CI").is_ok();
@@ -40,8 +45,7 @@ fn main() {
     // 2. Generate Man Page
     let man = Man::new(cli.clone());
     let mut man_buffer: Vec<u8> = Vec::new();
-    man.render(&mut man_buffer)
-        .expect("Failed to render man page");
+    render_man(&man, &mut man_buffer).expect("Failed to render man page");
     artifacts.push((man_dir.join("genlint.1"), man_buffer));
 
     // 3. Generate Help Message (HELP.md)
@@ -100,3 +104,41 @@ fn main() {
         }
     }
 }
+
+fn render_man(man: &Man, buffer: &mut Vec<u8>) -> std::io::Result<()> {
+    let mut page = Vec::new();
+    man.render(&mut page)?;
+    let page = String::from_utf8_lossy(&page);
+    let (head, tail) = page.split_at(page.find(".SH VERSION").unwrap_or(page.len()));
+    buffer.extend_from_slice(head.as_bytes());
+    render_rules_section(buffer);
+    buffer.extend_from_slice(tail.as_bytes());
+    Ok(())
+}
+
+// Emit the RULES section from the same metadata used by `genlint rules` and `genlint explain`
+fn render_rules_section(buffer: &mut Vec<u8>) {
+    let mut roff = String::from(".SH RULES\n");
+    for rule in rules::RULES {
+        roff.push_str(&format!(
+            ".TP\n\\fB{}\\fR ({}{})\n{}\n",
+            roff_escape(rule.code),
+            rule.check.default_severity().as_str(),
+            if rule.fixable { ", fixable" } else { "" },
+            roff_escape(rule.description)
+        ));
+        for param in rule.parameters {
+            roff.push_str(&format!(
+                ".br\n\\fI{}\\fR {} [default: {}]\n",
+                roff_escape(param.flag),
+                roff_escape(param.help),
+                roff_escape(param.default)
+            ));
+        }
+    }
+    buffer.extend_from_slice(roff.as_bytes());
+}
+
+fn roff_escape(s: &str) -> String {
+    s.replace('\\', "\\e").replace('-', "\\-")
+}
@@ -31,37 +31,86 @@ Register-ArgumentCompleter -Native -CommandName 'genlint' -ScriptBlock {
             [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Output file path')
             [CompletionResult]::new('-d', '-d', [CompletionResultType]::ParameterName, 'Disable specific checks')
             [CompletionResult]::new('--disable', '--disable', [CompletionResultType]::ParameterName, 'Disable specific checks')
+            [CompletionResult]::new('--severity', '--severity', [CompletionResultType]::ParameterName, 'Override the severity of specific checks (e.g. final-newline=error)')
             [CompletionResult]::new('-l', '-l', [CompletionResultType]::ParameterName, 'Maximum allowed line length')
             [CompletionResult]::new('--max-line-length', '--max-line-length', [CompletionResultType]::ParameterName, 'Maximum allowed line length')
+            [CompletionResult]::new('--tab-width', '--tab-width', [CompletionResultType]::ParameterName, 'Width of a tab stop when measuring line length')
             [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Maximum allowed consecutive blank lines')
             [CompletionResult]::new('--max-consecutive-blank', '--max-consecutive-blank', [CompletionResultType]::ParameterName, 'Maximum allowed consecutive blank lines')
+            [CompletionResult]::new('--max-lines', '--max-lines', [CompletionResultType]::ParameterName, 'Maximum allowed lines per file (set to 0 for no limit)')
+            [CompletionResult]::new('--max-file-size', '--max-file-size', [CompletionResultType]::ParameterName, 'Maximum allowed file size, e.g. 512K or 50M (set to 0 for no limit)')
+            [CompletionResult]::new('--max-path-length', '--max-path-length', [CompletionResultType]::ParameterName, 'Maximum allowed path length in characters (set to 0 for no limit)')
             [CompletionResult]::new('--max-errors', '--max-errors', [CompletionResultType]::ParameterName, 'Maximum number of errors to report (set to 0 for no limit)')
             [CompletionResult]::new('--max-warnings', '--max-warnings', [CompletionResultType]::ParameterName, 'Maximum number of warnings to report (set to 0 for no limit)')
             [CompletionResult]::new('--max-info', '--max-info', [CompletionResultType]::ParameterName, 'Maximum number of information to report (set to 0 for no limit)')
             [CompletionResult]::new('-m', '-m', [CompletionResultType]::ParameterName, 'Conflict marker style')
             [CompletionResult]::new('--conflict-marker-style', '--conflict-marker-style', [CompletionResultType]::ParameterName, 'Conflict marker style')
+            [CompletionResult]::new('--eol-style', '--eol-style', [CompletionResultType]::ParameterName, 'Expected line ending style')
+            [CompletionResult]::new('--final-newline', '--final-newline', [CompletionResultType]::ParameterName, 'Final newline policy: exactly one (require-single), at least one (require) or none (forbid)')
+            [CompletionResult]::new('--encoding', '--encoding', [CompletionResultType]::ParameterName, 'Skip like binary files (skip), report (utf8) or decode (detect) UTF-16 and UTF-32 files with a byte order mark')
+            [CompletionResult]::new('--bom', '--bom', [CompletionResultType]::ParameterName, 'UTF-8 byte order mark policy')
+            [CompletionResult]::new('--normalization-form', '--normalization-form', [CompletionResultType]::ParameterName, 'Unicode normalization form that every line must be in')
+            [CompletionResult]::new('--indent-style', '--indent-style', [CompletionResultType]::ParameterName, 'Expected indentation style')
+            [CompletionResult]::new('--indent-width', '--indent-width', [CompletionResultType]::ParameterName, 'Indentation width, used for space indentation and tab conversion')
+            [CompletionResult]::new('--whitespace', '--whitespace', [CompletionResultType]::ParameterName, 'Git whitespace classes to check on top of the defaults, like core.whitespace where a leading ''-'' turns a class off (e.g. ''tab-in-indent,-cr-at-eol''), or ''git'' to read core.whitespace from the repository (default: blank-at-eol,cr-at-eol)')
+            [CompletionResult]::new('--todo-keywords', '--todo-keywords', [CompletionResultType]::ParameterName, 'Technical-debt keywords reported by the todo rule')
+            [CompletionResult]::new('--todo-issue-pattern', '--todo-issue-pattern', [CompletionResultType]::ParameterName, 'Glob pattern of the issue reference required after a keyword, e.g. ''#[0-9]*'' for TODO(#123)')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Configuration file path (default: .genlint.toml if present)')
             [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Read input from stdin')
             [CompletionResult]::new('--stdin', '--stdin', [CompletionResultType]::ParameterName, 'Read input from stdin')
             [CompletionResult]::new('-a', '-a', [CompletionResultType]::ParameterName, 'Treat all input as text, bypassing binary detection')
             [CompletionResult]::new('--text', '--text', [CompletionResultType]::ParameterName, 'Treat all input as text, bypassing binary detection')
+            [CompletionResult]::new('--strict-conflict-markers', '--strict-conflict-markers', [CompletionResultType]::ParameterName, 'Report separator markers outside a conflict, except in Markdown and reStructuredText files')
+            [CompletionResult]::new('--fix', '--fix', [CompletionResultType]::ParameterName, 'Fix fixable issues in the input files in place')
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
             [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Scan files and write a .genlint.toml with thresholds chosen from them')
+            [CompletionResult]::new('rules', 'rules', [CompletionResultType]::ParameterValue, 'List all available rules')
+            [CompletionResult]::new('explain', 'explain', [CompletionResultType]::ParameterValue, 'Explain a rule with examples')
             [CompletionResult]::new('generate-completion', 'generate-completion', [CompletionResultType]::ParameterValue, 'Generate shell completions')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
+        'genlint;init' {
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Overwrite an existing configuration file')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'genlint;rules' {
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'genlint;explain' {
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
         'genlint;generate-completion' {
             [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
             break
         }
         'genlint;help' {
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Scan files and write a .genlint.toml with thresholds chosen from them')
+            [CompletionResult]::new('rules', 'rules', [CompletionResultType]::ParameterValue, 'List all available rules')
+            [CompletionResult]::new('explain', 'explain', [CompletionResultType]::ParameterValue, 'Explain a rule with examples')
             [CompletionResult]::new('generate-completion', 'generate-completion', [CompletionResultType]::ParameterValue, 'Generate shell completions')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
+        'genlint;help;init' {
+            break
+        }
+        'genlint;help;rules' {
+            break
+        }
+        'genlint;help;explain' {
+            break
+        }
         'genlint;help;generate-completion' {
             break
         }
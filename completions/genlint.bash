@@ -16,18 +16,36 @@ _genlint() {
             ",$1")
                 cmd="genlint"
                 ;;
+            genlint,explain)
+                cmd="genlint__subcmd__explain"
+                ;;
             genlint,generate-completion)
                 cmd="genlint__subcmd__generate__subcmd__completion"
                 ;;
             genlint,help)
                 cmd="genlint__subcmd__help"
                 ;;
+            genlint,init)
+                cmd="genlint__subcmd__init"
+                ;;
+            genlint,rules)
+                cmd="genlint__subcmd__rules"
+                ;;
+            genlint__subcmd__help,explain)
+                cmd="genlint__subcmd__help__subcmd__explain"
+                ;;
             genlint__subcmd__help,generate-completion)
                 cmd="genlint__subcmd__help__subcmd__generate__subcmd__completion"
                 ;;
             genlint__subcmd__help,help)
                 cmd="genlint__subcmd__help__subcmd__help"
                 ;;
+            genlint__subcmd__help,init)
+                cmd="genlint__subcmd__help__subcmd__init"
+                ;;
+            genlint__subcmd__help,rules)
+                cmd="genlint__subcmd__help__subcmd__rules"
+                ;;
             *)
                 ;;
         esac
@@ -35,7 +53,7 @@ _genlint() {
 
     case "${cmd}" in
         genlint)
-            opts="-s -i -e -f -o -d -a -l -c -m -h -V --stdin --input --exclude --format --output --disable --text --max-line-length --max-consecutive-blank --max-errors --max-warnings --max-info --conflict-marker-style --help --version generate-completion help"
+            opts="-s -i -e -f -o -d -a -l -c -m -h -V --stdin --input --exclude --format --output --disable --severity --text --max-line-length --tab-width --max-consecutive-blank --max-lines --max-file-size --max-path-length --max-errors --max-warnings --max-info --conflict-marker-style --strict-conflict-markers --eol-style --final-newline --encoding --bom --normalization-form --indent-style --indent-width --whitespace --todo-keywords --todo-issue-pattern --fix --config --help --version init rules explain generate-completion help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -74,11 +92,15 @@ _genlint() {
                     return 0
                     ;;
                 --disable)
-                    COMPREPLY=($(compgen -W "mix-indent trailing-space conflict-marker long-line consecutive-blank final-newline" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "mix-indent trailing-space conflict-marker long-line consecutive-blank final-newline eol bidi-char invisible-char control-char confusable normalization encoding bom indent max-lines max-file-size leading-blank whitespace-only-line space-before-tab indent-with-non-tab tab-in-indent blank-at-eof merge-artifact case-collision portable-path path-length secret todo header shebang" -- "${cur}"))
                     return 0
                     ;;
                 -d)
-                    COMPREPLY=($(compgen -W "mix-indent trailing-space conflict-marker long-line consecutive-blank final-newline" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "mix-indent trailing-space conflict-marker long-line consecutive-blank final-newline eol bidi-char invisible-char control-char confusable normalization encoding bom indent max-lines max-file-size leading-blank whitespace-only-line space-before-tab indent-with-non-tab tab-in-indent blank-at-eof merge-artifact case-collision portable-path path-length secret todo header shebang" -- "${cur}"))
+                    return 0
+                    ;;
+                --severity)
+                    COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
                 --max-line-length)
@@ -89,6 +111,10 @@ _genlint() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --tab-width)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --max-consecutive-blank)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -97,6 +123,18 @@ _genlint() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --max-lines)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --max-file-size)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --max-path-length)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --max-errors)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -110,11 +148,55 @@ _genlint() {
                     return 0
                     ;;
                 --conflict-marker-style)
-                    COMPREPLY=($(compgen -W "git git-diff3 jj jj-diff3 jj-snapshot" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "git git-diff3 jj jj-diff3 jj-snapshot auto detect" -- "${cur}"))
                     return 0
                     ;;
                 -m)
-                    COMPREPLY=($(compgen -W "git git-diff3 jj jj-diff3 jj-snapshot" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "git git-diff3 jj jj-diff3 jj-snapshot auto detect" -- "${cur}"))
+                    return 0
+                    ;;
+                --eol-style)
+                    COMPREPLY=($(compgen -W "lf crlf consistent" -- "${cur}"))
+                    return 0
+                    ;;
+                --final-newline)
+                    COMPREPLY=($(compgen -W "require-single require forbid" -- "${cur}"))
+                    return 0
+                    ;;
+                --encoding)
+                    COMPREPLY=($(compgen -W "skip utf8 detect" -- "${cur}"))
+                    return 0
+                    ;;
+                --bom)
+                    COMPREPLY=($(compgen -W "forbid require ignore" -- "${cur}"))
+                    return 0
+                    ;;
+                --normalization-form)
+                    COMPREPLY=($(compgen -W "nfc nfd nfkc nfkd" -- "${cur}"))
+                    return 0
+                    ;;
+                --indent-style)
+                    COMPREPLY=($(compgen -W "tabs spaces consistent" -- "${cur}"))
+                    return 0
+                    ;;
+                --indent-width)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --whitespace)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --todo-keywords)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --todo-issue-pattern)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --config)
+                    COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
                 *)
@@ -124,6 +206,20 @@ _genlint() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        genlint__subcmd__explain)
+            opts="-h --help mix-indent trailing-space conflict-marker long-line consecutive-blank final-newline eol bidi-char invisible-char control-char confusable normalization encoding bom indent max-lines max-file-size leading-blank whitespace-only-line space-before-tab indent-with-non-tab tab-in-indent blank-at-eof merge-artifact case-collision portable-path path-length secret todo header shebang"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         genlint__subcmd__generate__subcmd__completion)
             opts="-h --help bash elvish fish powershell zsh"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
@@ -139,7 +235,7 @@ _genlint() {
             return 0
             ;;
         genlint__subcmd__help)
-            opts="generate-completion help"
+            opts="init rules explain generate-completion help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -152,6 +248,20 @@ _genlint() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        genlint__subcmd__help__subcmd__explain)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         genlint__subcmd__help__subcmd__generate__subcmd__completion)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -180,6 +290,62 @@ _genlint() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        genlint__subcmd__help__subcmd__init)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        genlint__subcmd__help__subcmd__rules)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        genlint__subcmd__init)
+            opts="-h --force --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        genlint__subcmd__rules)
+            opts="-h --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
     esac
 }
 
@@ -1,6 +1,6 @@
 # Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
 function __fish_genlint_global_optspecs
-    string join \n s/stdin i/input= e/exclude= f/format= o/output= d/disable= a/text l/max-line-length= c/max-consecutive-blank= max-errors= max-warnings= max-info= m/conflict-marker-style= h/help V/version
+    string join \n s/stdin i/input= e/exclude= f/format= o/output= d/disable= severity= a/text l/max-line-length= tab-width= c/max-consecutive-blank= max-lines= max-file-size= max-path-length= max-errors= max-warnings= max-info= m/conflict-marker-style= strict-conflict-markers eol-style= final-newline= encoding= bom= normalization-form= indent-style= indent-width= whitespace= todo-keywords= todo-issue-pattern= fix config= h/help V/version
 end
 
 function __fish_genlint_needs_command
@@ -35,9 +35,39 @@ trailing-space\t''
 conflict-marker\t''
 long-line\t''
 consecutive-blank\t''
-final-newline\t''"
+final-newline\t''
+eol\t''
+bidi-char\t''
+invisible-char\t''
+control-char\t''
+confusable\t''
+normalization\t''
+encoding\t''
+bom\t''
+indent\t''
+max-lines\t''
+max-file-size\t''
+leading-blank\t''
+whitespace-only-line\t''
+space-before-tab\t''
+indent-with-non-tab\t''
+tab-in-indent\t''
+blank-at-eof\t''
+merge-artifact\t''
+case-collision\t''
+portable-path\t''
+path-length\t''
+secret\t''
+todo\t''
+header\t''
+shebang\t''"
+complete -c genlint -n "__fish_genlint_needs_command" -l severity -d 'Override the severity of specific checks (e.g. final-newline=error)' -r
 complete -c genlint -n "__fish_genlint_needs_command" -s l -l max-line-length -d 'Maximum allowed line length' -r
+complete -c genlint -n "__fish_genlint_needs_command" -l tab-width -d 'Width of a tab stop when measuring line length' -r
 complete -c genlint -n "__fish_genlint_needs_command" -s c -l max-consecutive-blank -d 'Maximum allowed consecutive blank lines' -r
+complete -c genlint -n "__fish_genlint_needs_command" -l max-lines -d 'Maximum allowed lines per file (set to 0 for no limit)' -r
+complete -c genlint -n "__fish_genlint_needs_command" -l max-file-size -d 'Maximum allowed file size, e.g. 512K or 50M (set to 0 for no limit)' -r
+complete -c genlint -n "__fish_genlint_needs_command" -l max-path-length -d 'Maximum allowed path length in characters (set to 0 for no limit)' -r
 complete -c genlint -n "__fish_genlint_needs_command" -l max-errors -d 'Maximum number of errors to report (set to 0 for no limit)' -r
 complete -c genlint -n "__fish_genlint_needs_command" -l max-warnings -d 'Maximum number of warnings to report (set to 0 for no limit)' -r
 complete -c genlint -n "__fish_genlint_needs_command" -l max-info -d 'Maximum number of information to report (set to 0 for no limit)' -r
@@ -45,13 +75,51 @@ complete -c genlint -n "__fish_genlint_needs_command" -s m -l conflict-marker-st
 git-diff3\t''
 jj\t''
 jj-diff3\t''
-jj-snapshot\t''"
+jj-snapshot\t''
+auto\t''
+detect\t''"
+complete -c genlint -n "__fish_genlint_needs_command" -l eol-style -d 'Expected line ending style' -r -f -a "lf\t''
+crlf\t''
+consistent\t''"
+complete -c genlint -n "__fish_genlint_needs_command" -l final-newline -d 'Final newline policy: exactly one (require-single), at least one (require) or none (forbid)' -r -f -a "require-single\t''
+require\t''
+forbid\t''"
+complete -c genlint -n "__fish_genlint_needs_command" -l encoding -d 'Skip like binary files (skip), report (utf8) or decode (detect) UTF-16 and UTF-32 files with a byte order mark' -r -f -a "skip\t''
+utf8\t''
+detect\t''"
+complete -c genlint -n "__fish_genlint_needs_command" -l bom -d 'UTF-8 byte order mark policy' -r -f -a "forbid\t''
+require\t''
+ignore\t''"
+complete -c genlint -n "__fish_genlint_needs_command" -l normalization-form -d 'Unicode normalization form that every line must be in' -r -f -a "nfc\t''
+nfd\t''
+nfkc\t''
+nfkd\t''"
+complete -c genlint -n "__fish_genlint_needs_command" -l indent-style -d 'Expected indentation style' -r -f -a "tabs\t''
+spaces\t''
+consistent\t''"
+complete -c genlint -n "__fish_genlint_needs_command" -l indent-width -d 'Indentation width, used for space indentation and tab conversion' -r
+complete -c genlint -n "__fish_genlint_needs_command" -l whitespace -d 'Git whitespace classes to check on top of the defaults, like core.whitespace where a leading \'-\' turns a class off (e.g. \'tab-in-indent,-cr-at-eol\'), or \'git\' to read core.whitespace from the repository (default: blank-at-eol,cr-at-eol)' -r
+complete -c genlint -n "__fish_genlint_needs_command" -l todo-keywords -d 'Technical-debt keywords reported by the todo rule' -r
+complete -c genlint -n "__fish_genlint_needs_command" -l todo-issue-pattern -d 'Glob pattern of the issue reference required after a keyword, e.g. \'#[0-9]*\' for TODO(#123)' -r
+complete -c genlint -n "__fish_genlint_needs_command" -l config -d 'Configuration file path (default: .genlint.toml if present)' -r -F
 complete -c genlint -n "__fish_genlint_needs_command" -s s -l stdin -d 'Read input from stdin'
 complete -c genlint -n "__fish_genlint_needs_command" -s a -l text -d 'Treat all input as text, bypassing binary detection'
+complete -c genlint -n "__fish_genlint_needs_command" -l strict-conflict-markers -d 'Report separator markers outside a conflict, except in Markdown and reStructuredText files'
+complete -c genlint -n "__fish_genlint_needs_command" -l fix -d 'Fix fixable issues in the input files in place'
 complete -c genlint -n "__fish_genlint_needs_command" -s h -l help -d 'Print help'
 complete -c genlint -n "__fish_genlint_needs_command" -s V -l version -d 'Print version'
+complete -c genlint -n "__fish_genlint_needs_command" -f -a "init" -d 'Scan files and write a .genlint.toml with thresholds chosen from them'
+complete -c genlint -n "__fish_genlint_needs_command" -f -a "rules" -d 'List all available rules'
+complete -c genlint -n "__fish_genlint_needs_command" -f -a "explain" -d 'Explain a rule with examples'
 complete -c genlint -n "__fish_genlint_needs_command" -f -a "generate-completion" -d 'Generate shell completions'
 complete -c genlint -n "__fish_genlint_needs_command" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c genlint -n "__fish_genlint_using_subcommand init" -l force -d 'Overwrite an existing configuration file'
+complete -c genlint -n "__fish_genlint_using_subcommand init" -s h -l help -d 'Print help'
+complete -c genlint -n "__fish_genlint_using_subcommand rules" -s h -l help -d 'Print help'
+complete -c genlint -n "__fish_genlint_using_subcommand explain" -s h -l help -d 'Print help'
 complete -c genlint -n "__fish_genlint_using_subcommand generate-completion" -s h -l help -d 'Print help'
-complete -c genlint -n "__fish_genlint_using_subcommand help; and not __fish_seen_subcommand_from generate-completion help" -f -a "generate-completion" -d 'Generate shell completions'
-complete -c genlint -n "__fish_genlint_using_subcommand help; and not __fish_seen_subcommand_from generate-completion help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c genlint -n "__fish_genlint_using_subcommand help; and not __fish_seen_subcommand_from init rules explain generate-completion help" -f -a "init" -d 'Scan files and write a .genlint.toml with thresholds chosen from them'
+complete -c genlint -n "__fish_genlint_using_subcommand help; and not __fish_seen_subcommand_from init rules explain generate-completion help" -f -a "rules" -d 'List all available rules'
+complete -c genlint -n "__fish_genlint_using_subcommand help; and not __fish_seen_subcommand_from init rules explain generate-completion help" -f -a "explain" -d 'Explain a rule with examples'
+complete -c genlint -n "__fish_genlint_using_subcommand help; and not __fish_seen_subcommand_from init rules explain generate-completion help" -f -a "generate-completion" -d 'Generate shell completions'
+complete -c genlint -n "__fish_genlint_using_subcommand help; and not __fish_seen_subcommand_from init rules explain generate-completion help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
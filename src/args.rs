@@ -1,5 +1,9 @@
-use crate::enums::{ConflictMarkerStyle, DisableCheck, Format};
-use clap::{Arg, ArgAction, ArgGroup, Command, arg, value_parser};
+use crate::enums::{
+    BomPolicy, ConflictMarkerStyle, DisableCheck, EncodingPolicy, EolStyle, FinalNewlinePolicy,
+    Format, IndentStyle, NormalizationForm, Severity,
+};
+use clap::builder::RangedU64ValueParser;
+use clap::{Arg, ArgAction, ArgGroup, Command, ValueEnum, arg, value_parser};
 use clap_complete::Shell;
 use std::path::PathBuf;
 
@@ -40,6 +44,12 @@ pub fn build_cli() -> Command {
                 .num_args(1..)
                 .value_parser(value_parser!(DisableCheck)),
         )
+        .arg(
+            arg!(--"severity" <RULE_LEVEL> "Override the severity of specific checks (e.g. final-newline=error)")
+                .value_delimiter(',')
+                .num_args(1..)
+                .value_parser(parse_severity_override),
+        )
         .arg(
             arg!(-a --"text" "Treat all input as text, bypassing binary detection")
                 .action(ArgAction::SetTrue),
@@ -49,11 +59,31 @@ pub fn build_cli() -> Command {
                 .value_parser(value_parser!(usize))
                 .default_value("120"),
         )
+        .arg(
+            arg!(--"tab-width" <NUM> "Width of a tab stop when measuring line length")
+                .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+                .default_value("4"),
+        )
         .arg(
             arg!(-c --"max-consecutive-blank" <NUM> "Maximum allowed consecutive blank lines")
                 .value_parser(value_parser!(usize))
                 .default_value("1"),
         )
+        .arg(
+            arg!(--"max-lines" <NUM> "Maximum allowed lines per file (set to 0 for no limit)")
+                .value_parser(value_parser!(usize))
+                .default_value("0"),
+        )
+        .arg(
+            arg!(--"max-file-size" <SIZE> "Maximum allowed file size, e.g. 512K or 50M (set to 0 for no limit)")
+                .value_parser(parse_size)
+                .default_value("0"),
+        )
+        .arg(
+            arg!(--"max-path-length" <NUM> "Maximum allowed path length in characters (set to 0 for no limit)")
+                .value_parser(value_parser!(usize))
+                .default_value("260"),
+        )
         .arg(
             arg!(--"max-errors" <NUM> "Maximum number of errors to report (set to 0 for no limit)")
                 .value_parser(value_parser!(usize))
@@ -74,6 +104,87 @@ pub fn build_cli() -> Command {
                 .value_parser(value_parser!(ConflictMarkerStyle))
                 .default_value("git"),
         )
+        .arg(
+            arg!(--"strict-conflict-markers" "Report separator markers outside a conflict, except in Markdown and reStructuredText files")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--"eol-style" <STYLE> "Expected line ending style")
+                .value_parser(value_parser!(EolStyle))
+                .default_value("consistent"),
+        )
+        .arg(
+            arg!(--"final-newline" <POLICY> "Final newline policy: exactly one (require-single), at least one (require) or none (forbid)")
+                .value_parser(value_parser!(FinalNewlinePolicy))
+                .default_value("require"),
+        )
+        .arg(
+            arg!(--"encoding" <POLICY> "Skip like binary files (skip), report (utf8) or decode (detect) UTF-16 and UTF-32 files with a byte order mark")
+                .value_parser(value_parser!(EncodingPolicy))
+                .default_value("skip"),
+        )
+        .arg(
+            arg!(--"bom" <POLICY> "UTF-8 byte order mark policy")
+                .value_parser(value_parser!(BomPolicy))
+                .default_value("forbid"),
+        )
+        .arg(
+            arg!(--"normalization-form" <FORM> "Unicode normalization form that every line must be in")
+                .value_parser(value_parser!(NormalizationForm))
+                .default_value("nfc"),
+        )
+        .arg(
+            arg!(--"indent-style" <STYLE> "Expected indentation style")
+                .value_parser(value_parser!(IndentStyle))
+                .default_value("consistent"),
+        )
+        .arg(
+            arg!(--"indent-width" <NUM> "Indentation width, used for space indentation and tab conversion")
+                .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+                .default_value("4"),
+        )
+        .arg(arg!(--"whitespace" <CLASSES> "Git whitespace classes to check on top of the defaults, like core.whitespace where a leading '-' turns a class off (e.g. 'tab-in-indent,-cr-at-eol'), or 'git' to read core.whitespace from the repository (default: blank-at-eol,cr-at-eol)"))
+        .arg(
+            arg!(--"todo-keywords" <KEYWORDS> "Technical-debt keywords reported by the todo rule")
+                .value_delimiter(',')
+                .num_args(1..)
+                .default_value("TODO,FIXME,XXX,HACK"),
+        )
+        .arg(arg!(--"todo-issue-pattern" <PATTERN> "Glob pattern of the issue reference required after a keyword, e.g. '#[0-9]*' for TODO(#123)"))
+        .arg(
+            arg!(--"fix" "Fix fixable issues in the input files in place")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("stdin"),
+        )
+        .arg(
+            arg!(--"config" <FILE> "Configuration file path (default: .genlint.toml if present)")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .subcommand(
+            Command::new("init")
+                .about("Scan files and write a .genlint.toml with thresholds chosen from them")
+                .arg(
+                    arg!(--"force" "Overwrite an existing configuration file")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("patterns")
+                        .num_args(1..)
+                        .default_value("**/*")
+                        .help("Glob patterns of files to scan"),
+                ),
+        )
+        .subcommand(Command::new("rules").about("List all available rules"))
+        .subcommand(
+            Command::new("explain")
+                .about("Explain a rule with examples")
+                .arg(
+                    Arg::new("rule")
+                        .value_parser(value_parser!(DisableCheck))
+                        .required(true)
+                        .help("Rule code to explain"),
+                ),
+        )
         .subcommand(
             Command::new("generate-completion")
                 .about("Generate shell completions")
@@ -91,3 +202,34 @@ pub fn build_cli() -> Command {
         )
         .subcommand_negates_reqs(true)
 }
+
+pub fn parse_severity_override(s: &str) -> Result<(DisableCheck, Severity), String> {
+    let (check, level) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected <RULE>=<LEVEL>, got '{}'", s))?;
+    let check = DisableCheck::from_str(check.trim(), false)
+        .map_err(|_| format!("unknown check '{}'", check.trim()))?;
+    let level = Severity::from_str(level.trim(), false)
+        .map_err(|_| format!("unknown severity '{}'", level.trim()))?;
+    Ok((check, level))
+}
+
+// Byte count with an optional binary suffix: K, M or G, optionally followed by B or iB
+pub fn parse_size(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let digits = s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let (number, unit) = s.split_at(digits);
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("expected a size like 512K or 50M, got '{}'", s))?;
+    let shift = match unit.to_ascii_uppercase().as_str() {
+        "" | "B" => 0,
+        "K" | "KB" | "KIB" => 10,
+        "M" | "MB" | "MIB" => 20,
+        "G" | "GB" | "GIB" => 30,
+        _ => return Err(format!("unknown size unit '{}'", unit)),
+    };
+    number
+        .checked_mul(1 << shift)
+        .ok_or_else(|| format!("size '{}' is too large", s))
+}
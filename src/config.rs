@@ -0,0 +1,299 @@
+use clap::ValueEnum;
+use glob::Pattern;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::args::parse_size;
+use crate::enums::{
+    BomPolicy, ConflictMarkerStyle, DisableCheck, EncodingPolicy, EolStyle, FinalNewlinePolicy,
+    IndentStyle, NormalizationForm, Severity,
+};
+
+pub const DEFAULT_CONFIG_FILE: &str = ".genlint.toml";
+pub const DEFAULT_HEADER_LINES: usize = 10;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+struct RawConfig {
+    disable: Option<Vec<String>>,
+    severity: BTreeMap<String, String>,
+    text: Option<bool>,
+    max_line_length: Option<usize>,
+    tab_width: Option<usize>,
+    max_consecutive_blank: Option<usize>,
+    max_lines: Option<usize>,
+    max_file_size: Option<RawSize>,
+    max_path_length: Option<usize>,
+    max_errors: Option<usize>,
+    max_warnings: Option<usize>,
+    max_info: Option<usize>,
+    conflict_marker_style: Option<String>,
+    strict_conflict_markers: Option<bool>,
+    eol_style: Option<String>,
+    final_newline: Option<String>,
+    encoding: Option<String>,
+    bom: Option<String>,
+    normalization_form: Option<String>,
+    indent_style: Option<String>,
+    indent_width: Option<usize>,
+    whitespace: Option<String>,
+    secret_allowlist: Vec<String>,
+    todo_keywords: Option<Vec<String>>,
+    todo_issue_pattern: Option<String>,
+    header: Option<Vec<String>>,
+    header_comment: Option<String>,
+    header_lines: Option<usize>,
+    allowed_control_chars: Vec<String>,
+    overrides: Vec<RawOverride>,
+}
+
+// Sizes are either a byte count or a string with a unit, like on the command line
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawSize {
+    Bytes(u64),
+    Text(String),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct RawOverride {
+    files: Vec<String>,
+    disable: Option<Vec<String>>,
+    tab_width: Option<usize>,
+    indent_style: Option<String>,
+    indent_width: Option<usize>,
+    header: Option<Vec<String>>,
+    header_comment: Option<String>,
+    header_lines: Option<usize>,
+    allowed_control_chars: Option<Vec<String>>,
+}
+
+// Every key mirrors the long name of a command-line option, which takes precedence when given,
+// except for options that are only practical in a file, such as `secret-allowlist`, `header` and
+// `allowed-control-chars`
+#[derive(Debug, Default)]
+pub struct Config {
+    pub disable: Option<Vec<DisableCheck>>,
+    pub severities: Vec<(DisableCheck, Severity)>,
+    pub text: Option<bool>,
+    pub max_line_length: Option<usize>,
+    pub tab_width: Option<usize>,
+    pub max_consecutive_blank: Option<usize>,
+    pub max_lines: Option<usize>,
+    pub max_file_size: Option<u64>,
+    pub max_path_length: Option<usize>,
+    pub max_errors: Option<usize>,
+    pub max_warnings: Option<usize>,
+    pub max_info: Option<usize>,
+    pub conflict_marker_style: Option<ConflictMarkerStyle>,
+    pub strict_conflict_markers: Option<bool>,
+    pub eol_style: Option<EolStyle>,
+    pub final_newline: Option<FinalNewlinePolicy>,
+    pub encoding: Option<EncodingPolicy>,
+    pub bom: Option<BomPolicy>,
+    pub normalization_form: Option<NormalizationForm>,
+    pub indent_style: Option<IndentStyle>,
+    pub indent_width: Option<usize>,
+    pub whitespace: Option<String>,
+    pub secret_allowlist: Vec<Pattern>,
+    pub todo_keywords: Option<Vec<String>>,
+    pub todo_issue_pattern: Option<Pattern>,
+    pub header: Option<Vec<String>>,
+    pub header_comment: Option<String>,
+    pub header_lines: Option<usize>,
+    pub allowed_control_chars: Vec<char>,
+    pub overrides: Vec<Override>,
+}
+
+// Options of an `[[overrides]]` table, applied to files matching any of its glob patterns
+#[derive(Debug)]
+pub struct Override {
+    pub files: Vec<Pattern>,
+    // Rules disabled in addition to the ones disabled for every file
+    pub disable: Option<Vec<DisableCheck>>,
+    pub tab_width: Option<usize>,
+    pub indent_style: Option<IndentStyle>,
+    pub indent_width: Option<usize>,
+    pub header: Option<Vec<String>>,
+    pub header_comment: Option<String>,
+    pub header_lines: Option<usize>,
+    pub allowed_control_chars: Option<Vec<char>>,
+}
+
+impl Override {
+    pub fn matches(&self, path: &Path) -> bool {
+        self.files.iter().any(|pattern| pattern.matches_path(path))
+    }
+}
+
+fn parse_value<T: ValueEnum>(key: &str, value: &str) -> Result<T, String> {
+    T::from_str(value, false).map_err(|_| format!("invalid value '{}' for '{}'", value, key))
+}
+
+fn parse_checks(checks: Option<Vec<String>>) -> Result<Option<Vec<DisableCheck>>, String> {
+    checks
+        .map(|checks| {
+            checks
+                .iter()
+                .map(|c| parse_value::<DisableCheck>("disable", c))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+}
+
+fn parse_width(key: &str, width: Option<usize>) -> Result<Option<usize>, String> {
+    match width {
+        Some(0) => Err(format!("invalid value '0' for '{}'", key)),
+        width => Ok(width),
+    }
+}
+
+fn parse_patterns(patterns: &[String]) -> Result<Vec<Pattern>, String> {
+    patterns
+        .iter()
+        .map(|p| Pattern::new(p).map_err(|e| format!("invalid pattern '{}': {}", p, e)))
+        .collect()
+}
+
+// Characters are given as themselves, such as "\f", or as code points like "U+000C"
+fn parse_chars(key: &str, chars: &[String]) -> Result<Vec<char>, String> {
+    chars
+        .iter()
+        .map(|s| {
+            let mut iter = s.chars();
+            match (iter.next(), iter.next()) {
+                (Some(c), None) => Some(c),
+                _ => s
+                    .strip_prefix("U+")
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(char::from_u32),
+            }
+            .ok_or_else(|| format!("invalid value '{}' for '{}'", s.escape_debug(), key))
+        })
+        .collect()
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        Self::parse(&content).map_err(|e| format!("Invalid config '{}': {}", path.display(), e))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let raw: RawConfig = toml::from_str(content).map_err(|e| e.message().to_string())?;
+
+        let disable = parse_checks(raw.disable)?;
+        let severities = raw
+            .severity
+            .iter()
+            .map(|(check, level)| {
+                Ok((
+                    parse_value::<DisableCheck>("severity", check)?,
+                    parse_value::<Severity>(&format!("severity.{}", check), level)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let max_file_size = raw
+            .max_file_size
+            .map(|size| match size {
+                RawSize::Bytes(bytes) => Ok(bytes),
+                RawSize::Text(s) => parse_size(&s)
+                    .map_err(|e| format!("invalid value '{}' for 'max-file-size': {}", s, e)),
+            })
+            .transpose()?;
+        let conflict_marker_style = raw
+            .conflict_marker_style
+            .map(|s| parse_value::<ConflictMarkerStyle>("conflict-marker-style", &s))
+            .transpose()?;
+        let eol_style = raw
+            .eol_style
+            .map(|s| parse_value::<EolStyle>("eol-style", &s))
+            .transpose()?;
+        let final_newline = raw
+            .final_newline
+            .map(|s| parse_value::<FinalNewlinePolicy>("final-newline", &s))
+            .transpose()?;
+        let encoding = raw
+            .encoding
+            .map(|s| parse_value::<EncodingPolicy>("encoding", &s))
+            .transpose()?;
+        let bom = raw
+            .bom
+            .map(|s| parse_value::<BomPolicy>("bom", &s))
+            .transpose()?;
+        let normalization_form = raw
+            .normalization_form
+            .map(|s| parse_value::<NormalizationForm>("normalization-form", &s))
+            .transpose()?;
+        let indent_style = raw
+            .indent_style
+            .map(|s| parse_value::<IndentStyle>("indent-style", &s))
+            .transpose()?;
+        let overrides = raw
+            .overrides
+            .into_iter()
+            .map(|raw| {
+                Ok(Override {
+                    files: parse_patterns(&raw.files)?,
+                    disable: parse_checks(raw.disable)?,
+                    tab_width: parse_width("tab-width", raw.tab_width)?,
+                    indent_style: raw
+                        .indent_style
+                        .map(|s| parse_value::<IndentStyle>("indent-style", &s))
+                        .transpose()?,
+                    indent_width: parse_width("indent-width", raw.indent_width)?,
+                    header: raw.header,
+                    header_comment: raw.header_comment,
+                    header_lines: parse_width("header-lines", raw.header_lines)?,
+                    allowed_control_chars: raw
+                        .allowed_control_chars
+                        .map(|chars| parse_chars("allowed-control-chars", &chars))
+                        .transpose()?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            disable,
+            severities,
+            text: raw.text,
+            max_line_length: raw.max_line_length,
+            tab_width: parse_width("tab-width", raw.tab_width)?,
+            max_consecutive_blank: raw.max_consecutive_blank,
+            max_lines: raw.max_lines,
+            max_file_size,
+            max_path_length: raw.max_path_length,
+            max_errors: raw.max_errors,
+            max_warnings: raw.max_warnings,
+            max_info: raw.max_info,
+            conflict_marker_style,
+            strict_conflict_markers: raw.strict_conflict_markers,
+            eol_style,
+            final_newline,
+            encoding,
+            bom,
+            normalization_form,
+            indent_style,
+            indent_width: parse_width("indent-width", raw.indent_width)?,
+            whitespace: raw.whitespace,
+            secret_allowlist: parse_patterns(&raw.secret_allowlist)?,
+            todo_keywords: raw.todo_keywords,
+            todo_issue_pattern: raw
+                .todo_issue_pattern
+                .map(|p| parse_patterns(&[p]).map(|mut patterns| patterns.remove(0)))
+                .transpose()?,
+            header: raw.header,
+            header_comment: raw.header_comment,
+            header_lines: parse_width("header-lines", raw.header_lines)?,
+            allowed_control_chars: parse_chars(
+                "allowed-control-chars",
+                &raw.allowed_control_chars,
+            )?,
+            overrides,
+        })
+    }
+}
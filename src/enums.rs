@@ -7,7 +7,7 @@ pub enum Format {
     Plain,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ValueEnum)]
 pub enum DisableCheck {
     MixIndent,
     TrailingSpace,
@@ -15,6 +15,31 @@ pub enum DisableCheck {
     LongLine,
     ConsecutiveBlank,
     FinalNewline,
+    Eol,
+    BidiChar,
+    InvisibleChar,
+    ControlChar,
+    Confusable,
+    Normalization,
+    Encoding,
+    Bom,
+    Indent,
+    MaxLines,
+    MaxFileSize,
+    LeadingBlank,
+    WhitespaceOnlyLine,
+    SpaceBeforeTab,
+    IndentWithNonTab,
+    TabInIndent,
+    BlankAtEof,
+    MergeArtifact,
+    CaseCollision,
+    PortablePath,
+    PathLength,
+    Secret,
+    Todo,
+    Header,
+    Shebang,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
@@ -24,4 +49,115 @@ pub enum ConflictMarkerStyle {
     Jj,
     JjDiff3,
     JjSnapshot,
+    Auto,
+    Detect,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum EolStyle {
+    Lf,
+    Crlf,
+    Consistent,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum EncodingPolicy {
+    Skip,
+    Utf8,
+    Detect,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum BomPolicy {
+    Forbid,
+    Require,
+    Ignore,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum FinalNewlinePolicy {
+    RequireSingle,
+    Require,
+    Forbid,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl NormalizationForm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Nfc => "NFC",
+            Self::Nfd => "NFD",
+            Self::Nfkc => "NFKC",
+            Self::Nfkd => "NFKD",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum IndentStyle {
+    Tabs,
+    Spaces,
+    Consistent,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Severity {
+    Error,
+    Warning,
+    Information,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Information => "information",
+        }
+    }
+}
+
+impl DisableCheck {
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            Self::ConflictMarker
+            | Self::BidiChar
+            | Self::Encoding
+            | Self::MergeArtifact
+            | Self::CaseCollision
+            | Self::PortablePath
+            | Self::Secret => Severity::Error,
+            Self::MixIndent
+            | Self::TrailingSpace
+            | Self::Eol
+            | Self::InvisibleChar
+            | Self::ControlChar
+            | Self::Confusable
+            | Self::Normalization
+            | Self::Bom
+            | Self::Indent
+            | Self::MaxLines
+            | Self::MaxFileSize
+            | Self::WhitespaceOnlyLine
+            | Self::SpaceBeforeTab
+            | Self::IndentWithNonTab
+            | Self::TabInIndent
+            | Self::BlankAtEof
+            | Self::PathLength
+            | Self::Header
+            | Self::Shebang => Severity::Warning,
+            Self::LongLine
+            | Self::ConsecutiveBlank
+            | Self::FinalNewline
+            | Self::LeadingBlank
+            | Self::Todo => Severity::Information,
+        }
+    }
 }
@@ -0,0 +1,98 @@
+use std::fs;
+use std::io::{Cursor, ErrorKind};
+use std::path::Path;
+
+use crate::lint::lint_lines;
+use crate::types::{Fix, LintOptions, LintRunner};
+
+// Fixes of different rules may touch the same lines, so files are re-linted until nothing changes
+const MAX_FIX_PASSES: usize = 10;
+
+pub fn apply_fixes(content: &str, fixes: &[&Fix]) -> (String, usize) {
+    let mut sorted = fixes.to_vec();
+    sorted.sort_by_key(|fix| (fix.lnum, fix.end_lnum));
+
+    let (mut bom, content) = match content.strip_prefix('\u{FEFF}') {
+        Some(rest) => (true, rest),
+        None => (false, content),
+    };
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let mut output = String::with_capacity(content.len());
+    let mut next_line = 0;
+    let mut applied = 0;
+    for fix in sorted {
+        // Skip fixes overlapping an applied one, they are retried on the next pass
+        if fix.lnum < next_line || fix.end_lnum > lines.len() {
+            continue;
+        }
+        // A fix leaving the text as it is would otherwise be applied on every pass
+        if fix.bom.is_none_or(|fix_bom| fix_bom == bom)
+            && lines[fix.lnum..fix.end_lnum].concat() == fix.replacement
+        {
+            continue;
+        }
+        if let Some(fix_bom) = fix.bom {
+            bom = fix_bom;
+        }
+        lines[next_line..fix.lnum]
+            .iter()
+            .for_each(|line| output.push_str(line));
+        output.push_str(&fix.replacement);
+        next_line = fix.end_lnum;
+        applied += 1;
+    }
+    lines[next_line..]
+        .iter()
+        .for_each(|line| output.push_str(line));
+    if bom {
+        output.insert(0, '\u{FEFF}');
+    }
+    (output, applied)
+}
+
+pub fn fix_file(path: &Path, opts: &LintOptions) -> Result<usize, String> {
+    let filename = path.to_string_lossy();
+    // Limits only apply to reporting, every fixable issue should be fixed
+    let fix_opts = LintOptions {
+        max_errors: 0,
+        max_warnings: 0,
+        max_info: 0,
+        ..opts.clone()
+    };
+
+    let mut total = 0;
+    for _ in 0..MAX_FIX_PASSES {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            // Rewriting lossily decoded text would corrupt the file
+            Err(e) if e.kind() == ErrorKind::InvalidData => {
+                return Err(format!("Cannot fix '{}': not valid UTF-8", filename));
+            }
+            Err(e) => return Err(format!("Failed to read '{}': {}", filename, e)),
+        };
+        if !opts.text_mode && content.as_bytes()[..content.len().min(8192)].contains(&0) {
+            break;
+        }
+
+        let mut runner = LintRunner::new();
+        lint_lines(
+            &filename,
+            Cursor::new(content.as_bytes()),
+            &mut runner,
+            &fix_opts,
+        );
+        let fixes: Vec<&Fix> = runner
+            .diagnostics
+            .iter()
+            .filter_map(|diag| diag.fixes.as_ref())
+            .flatten()
+            .collect();
+        let (fixed, applied) = apply_fixes(&content, &fixes);
+        if applied == 0 {
+            break;
+        }
+        fs::write(path, fixed).map_err(|e| format!("Failed to write '{}': {}", filename, e))?;
+        total += applied;
+    }
+    Ok(total)
+}
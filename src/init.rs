@@ -0,0 +1,275 @@
+use glob::{MatchOptions, glob_with};
+use std::fs;
+use std::path::Path;
+
+use crate::util::calculate_width;
+
+const DEFAULT_MAX_LINE_LENGTH: usize = 120;
+const DEFAULT_MAX_CONSECUTIVE_BLANK: usize = 1;
+const DEFAULT_TAB_WIDTH: usize = 4;
+
+#[derive(Debug, Default)]
+struct ScanStats {
+    files: usize,
+    widths: Vec<usize>,
+    max_consecutive_blank: usize,
+    space_indented: usize,
+    tab_indented: usize,
+    mix_indented: usize,
+    space_indent_gcd: usize,
+    tab_first_indented: usize,
+    space_first_indented: usize,
+    inconsistent_indent: usize,
+    trailing_space: usize,
+    whitespace_only: usize,
+    leading_blank: usize,
+    missing_final_newline: usize,
+    mixed_eol: usize,
+    bom: usize,
+    lf: usize,
+    crlf: usize,
+    cr: usize,
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl ScanStats {
+    fn scan_file(&mut self, content: &str) {
+        self.files += 1;
+        // A byte order mark is reported by the bom check, not as part of the first line
+        let content = match content.strip_prefix('\u{feff}') {
+            Some(content) => {
+                self.bom += 1;
+                content
+            }
+            None => content,
+        };
+        let (lf, crlf, cr) = (self.lf, self.crlf, self.cr);
+        let mut blank_run = 0;
+        let (mut tab_first, mut space_first) = (false, false);
+        let mut seen_content = false;
+        for (lnum, line) in content.split_inclusive('\n').enumerate() {
+            let trimmed = line.trim_end_matches(['\r', '\n']);
+            if line.ends_with("\r\n") {
+                self.crlf += 1;
+            } else if line.ends_with('\n') {
+                self.lf += 1;
+            }
+            self.cr += trimmed.matches('\r').count();
+
+            self.widths
+                .push(calculate_width(trimmed, DEFAULT_TAB_WIDTH));
+
+            if trimmed.is_empty() {
+                blank_run += 1;
+                self.max_consecutive_blank = self.max_consecutive_blank.max(blank_run);
+                continue;
+            }
+            blank_run = 0;
+
+            let content = trimmed.trim_start_matches([' ', '\t']);
+            let indent = &trimmed[..trimmed.len() - content.len()];
+            if indent.contains(' ') && indent.contains('\t') {
+                self.mix_indented += 1;
+            }
+            if content.is_empty() {
+                self.whitespace_only += 1;
+                continue;
+            }
+            if !seen_content && lnum > 0 {
+                self.leading_blank += 1;
+            }
+            seen_content = true;
+
+            tab_first |= indent.starts_with('\t');
+            space_first |= indent.starts_with(' ');
+            match (indent.contains(' '), indent.contains('\t')) {
+                (true, false) => {
+                    self.space_indented += 1;
+                    self.space_indent_gcd = gcd(self.space_indent_gcd, indent.len());
+                }
+                (false, true) => self.tab_indented += 1,
+                _ => {}
+            }
+            if trimmed.ends_with([' ', '\t']) {
+                self.trailing_space += 1;
+            }
+        }
+        self.tab_first_indented += tab_first as usize;
+        self.space_first_indented += space_first as usize;
+        self.inconsistent_indent += (tab_first && space_first) as usize;
+        let endings = [self.lf - lf, self.crlf - crlf, self.cr - cr];
+        self.mixed_eol += (endings.iter().filter(|&&count| count > 0).count() > 1) as usize;
+        if !content.is_empty() && !content.ends_with(['\n', '\r']) {
+            self.missing_final_newline += 1;
+        }
+    }
+
+    fn percentile(&self, p: usize) -> usize {
+        if self.widths.is_empty() {
+            return 0;
+        }
+        let index = (self.widths.len() * p).div_ceil(100).saturating_sub(1);
+        self.widths[index.min(self.widths.len() - 1)]
+    }
+
+    fn render(&mut self) -> String {
+        self.widths.sort_unstable();
+        let max_width = self.widths.last().copied().unwrap_or(0);
+
+        let mut disables = Vec::new();
+        if self.mix_indented > 0 {
+            disables.push(format!(
+                "    \"mix-indent\", # {} lines mix tabs and spaces",
+                self.mix_indented
+            ));
+        }
+        if self.trailing_space > 0 {
+            disables.push(format!(
+                "    \"trailing-space\", # {} lines end with whitespace",
+                self.trailing_space
+            ));
+        }
+        if self.inconsistent_indent > 0 {
+            disables.push(format!(
+                "    \"indent\", # {} files mix tab and space indentation",
+                self.inconsistent_indent
+            ));
+        }
+        if self.mixed_eol > 0 {
+            disables.push(format!(
+                "    \"eol\", # {} files mix line endings",
+                self.mixed_eol
+            ));
+        }
+        if self.bom > 0 {
+            disables.push(format!(
+                "    \"bom\", # {} files start with a UTF-8 byte order mark",
+                self.bom
+            ));
+        }
+        if self.whitespace_only > 0 {
+            disables.push(format!(
+                "    \"whitespace-only-line\", # {} lines contain only whitespace",
+                self.whitespace_only
+            ));
+        }
+        if self.leading_blank > 0 {
+            disables.push(format!(
+                "    \"leading-blank\", # {} files start with blank lines",
+                self.leading_blank
+            ));
+        }
+        if self.missing_final_newline > 0 {
+            disables.push(format!(
+                "    \"final-newline\", # {} files miss a final newline",
+                self.missing_final_newline
+            ));
+        }
+
+        let mut out = String::new();
+        out.push_str("# genlint configuration generated by `genlint init`.\n");
+        out.push_str(&format!(
+            "# Thresholds and disabled checks were chosen from the {} scanned files.\n",
+            self.files
+        ));
+        out.push_str("# Checks that are not scanned for, like secret, confusable, control-char\n");
+        out.push_str("# and normalization, may still report existing issues. Tighten the\n");
+        out.push_str("# thresholds over time. Command-line options take precedence.\n\n");
+
+        out.push_str(&format!(
+            "# Line width distribution: p50 = {}, p90 = {}, p99 = {}, max = {}\n",
+            self.percentile(50),
+            self.percentile(90),
+            self.percentile(99),
+            max_width
+        ));
+        out.push_str(&format!(
+            "max-line-length = {}\n\n",
+            max_width.max(DEFAULT_MAX_LINE_LENGTH)
+        ));
+
+        out.push_str(&format!(
+            "# Longest run of consecutive blank lines: {}\n",
+            self.max_consecutive_blank
+        ));
+        out.push_str(&format!(
+            "max-consecutive-blank = {}\n\n",
+            self.max_consecutive_blank
+                .max(DEFAULT_MAX_CONSECUTIVE_BLANK)
+        ));
+
+        out.push_str(&format!(
+            "# Indentation: {} lines with spaces, {} lines with tabs, {} lines mixed\n",
+            self.space_indented, self.tab_indented, self.mix_indented
+        ));
+        // The spaces style also flags tabs after leading spaces, so mixed indents rule it out
+        match (self.space_first_indented, self.tab_first_indented) {
+            (0, 0) => out.push_str("indent-style = \"consistent\"\n\n"),
+            (_, 0) if self.space_indent_gcd > 0 && self.mix_indented == 0 => {
+                out.push_str(&format!(
+                    "indent-style = \"spaces\"\nindent-width = {}\n\n",
+                    self.space_indent_gcd
+                ))
+            }
+            (0, _) => out.push_str("indent-style = \"tabs\"\n\n"),
+            _ => out.push_str("indent-style = \"consistent\"\n\n"),
+        }
+        out.push_str(&format!(
+            "# Line endings: {} LF, {} CRLF, {} CR\n",
+            self.lf, self.crlf, self.cr
+        ));
+        let eol_style = match (self.lf, self.crlf, self.cr) {
+            (_, 0, 0) => "lf",
+            (0, _, 0) => "crlf",
+            _ => "consistent",
+        };
+        out.push_str(&format!("eol-style = \"{}\"\n\n", eol_style));
+
+        if disables.is_empty() {
+            out.push_str("# Checks with existing violations can be disabled here\n");
+            out.push_str("# disable = []\n\n");
+        } else {
+            out.push_str("# Checks with existing violations\n");
+            out.push_str(&format!("disable = [\n{}\n]\n\n", disables.join("\n")));
+        }
+
+        out.push_str("# Override the severity of specific checks\n");
+        out.push_str("# [severity]\n");
+        out.push_str("# final-newline = \"error\"\n");
+        out
+    }
+}
+
+pub fn init_config(path: &Path, patterns: &[String], force: bool) -> Result<usize, String> {
+    if path.exists() && !force {
+        return Err(format!(
+            "'{}' already exists, use --force to overwrite it",
+            path.display()
+        ));
+    }
+
+    let options = MatchOptions {
+        require_literal_leading_dot: true,
+        ..MatchOptions::new()
+    };
+    let mut stats = ScanStats::default();
+    for pattern in patterns {
+        let entries = glob_with(pattern, options)
+            .map_err(|e| format!("Invalid pattern '{}': {}", pattern, e))?;
+        for path in entries.flatten().filter(|p| p.is_file()) {
+            // Skip binary files like linting does, as well as files that are not valid UTF-8
+            if let Ok(content) = fs::read_to_string(&path)
+                && !content.as_bytes()[..content.len().min(8192)].contains(&0)
+            {
+                stats.scan_file(&content);
+            }
+        }
+    }
+
+    fs::write(path, stats.render())
+        .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+    Ok(stats.files)
+}
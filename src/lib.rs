@@ -1,4 +1,7 @@
 pub mod enums;
+pub mod fix;
 pub mod lint;
+pub mod rules;
+pub mod secret;
 pub mod types;
 pub mod util;
@@ -1,64 +1,1019 @@
+use glob::Pattern;
 use std::cmp::max;
-use std::io::BufRead;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, Cursor};
+use std::path::{Component, Path, PathBuf};
+use unicode_normalization::{
+    IsNormalized, UnicodeNormalization, is_nfc, is_nfc_quick, is_nfd_quick, is_nfkc_quick,
+    is_nfkd_quick,
+};
 
-use crate::enums::{ConflictMarkerStyle, DisableCheck::*};
-use crate::types::{Diagnostic, Helper, LintOptions, LintRunner};
-use crate::util::{calculate_width, char_col_at_visual_width, find_non_space_col};
+use crate::secret::{PRIVATE_KEY, find_secrets, mask_secret};
 
-pub fn lint_lines<R: BufRead>(
+use crate::enums::{
+    BomPolicy, ConflictMarkerStyle, DisableCheck::*, EncodingPolicy, EolStyle, FinalNewlinePolicy,
+    IndentStyle, NormalizationForm,
+};
+use crate::types::{Diagnostic, Fix, Helper, LintOptions, LintRunner};
+use crate::util::{
+    bidi_control_name, calculate_width, char_col_at_visual_width, confusable_char,
+    control_char_name, current_year, find_non_space_col, invisible_char_name,
+};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+// Offending line endings reported one by one before the rest are aggregated
+const EOL_REPORT_LIMIT: usize = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LineEnding {
+    Lf,
+    Crlf,
+    Cr,
+}
+
+impl LineEnding {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Lf => "LF",
+            Self::Crlf => "CRLF",
+            Self::Cr => "CR",
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::Crlf => "\r\n",
+            Self::Cr => "\r",
+        }
+    }
+}
+
+// Line endings of a line as (char column, ending); lone CRs split a line read up to LF
+fn line_endings(line: &str) -> Vec<(usize, LineEnding)> {
+    let mut endings = Vec::new();
+    let mut chars = line.chars().enumerate().peekable();
+    while let Some((col, c)) = chars.next() {
+        match c {
+            '\r' if chars.peek().is_some_and(|&(_, next)| next == '\n') => {
+                endings.push((col, LineEnding::Crlf));
+                chars.next();
+            }
+            '\r' => endings.push((col, LineEnding::Cr)),
+            '\n' => endings.push((col, LineEnding::Lf)),
+            _ => {}
+        }
+    }
+    endings
+}
+
+fn convert_line_endings(line: &str, ending: LineEnding) -> String {
+    line.replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .replace('\n', ending.as_str())
+}
+
+// Replace the indentation of a line by tabs (plus alignment spaces) or spaces of the same width
+fn convert_indentation(line: &str, to_tabs: bool, width: usize) -> String {
+    let content = line.trim_start_matches([' ', '\t']);
+    let indent = &line[..line.len() - content.len()];
+    let visual_width = indent.chars().fold(0, |w, c| match c {
+        '\t' => (w / width + 1) * width,
+        _ => w + 1,
+    });
+    let indent = if to_tabs {
+        "\t".repeat(visual_width / width) + &" ".repeat(visual_width % width)
+    } else {
+        " ".repeat(visual_width)
+    };
+    indent + content
+}
+
+fn indent_name(tabs: bool) -> &'static str {
+    if tabs { "tabs" } else { "spaces" }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum UnicodeEncoding {
+    Utf16Le,
+    Utf16Be,
+    Utf32Le,
+    Utf32Be,
+}
+
+impl UnicodeEncoding {
+    // UTF-32 marks must be checked first since the UTF-32LE mark starts with the UTF-16LE one
+    fn from_bom(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0xFF, 0xFE, 0x00, 0x00, ..] => Some(Self::Utf32Le),
+            [0x00, 0x00, 0xFE, 0xFF, ..] => Some(Self::Utf32Be),
+            [0xFF, 0xFE, ..] => Some(Self::Utf16Le),
+            [0xFE, 0xFF, ..] => Some(Self::Utf16Be),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Utf16Le => "UTF-16LE",
+            Self::Utf16Be => "UTF-16BE",
+            Self::Utf32Le => "UTF-32LE",
+            Self::Utf32Be => "UTF-32BE",
+        }
+    }
+
+    fn bom_len(&self) -> usize {
+        match self {
+            Self::Utf16Le | Self::Utf16Be => 2,
+            Self::Utf32Le | Self::Utf32Be => 4,
+        }
+    }
+
+    fn decode(&self, bytes: &[u8]) -> String {
+        let bytes = &bytes[self.bom_len()..];
+        match self {
+            Self::Utf16Le | Self::Utf16Be => {
+                let units: Vec<u16> = bytes
+                    .chunks(2)
+                    .map(|pair| {
+                        let pair = [pair[0], *pair.get(1).unwrap_or(&0)];
+                        if *self == Self::Utf16Le {
+                            u16::from_le_bytes(pair)
+                        } else {
+                            u16::from_be_bytes(pair)
+                        }
+                    })
+                    .collect();
+                String::from_utf16_lossy(&units)
+            }
+            Self::Utf32Le | Self::Utf32Be => bytes
+                .chunks(4)
+                .map(|chunk| {
+                    let mut quad = [0u8; 4];
+                    quad[..chunk.len()].copy_from_slice(chunk);
+                    let value = if *self == Self::Utf32Le {
+                        u32::from_le_bytes(quad)
+                    } else {
+                        u32::from_be_bytes(quad)
+                    };
+                    char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER)
+                })
+                .collect(),
+        }
+    }
+}
+
+// Markdown and reStructuredText use marker-like lines for headings and transitions
+fn is_markup_file(filename: &str) -> bool {
+    Path::new(filename)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            ["md", "markdown", "rst"]
+                .iter()
+                .any(|markup| ext.eq_ignore_ascii_case(markup))
+        })
+}
+
+// Message of a conflict diagnostic, prefixed with the name of the conflict marker style
+fn conflict_message(style: Option<&str>, message: &str) -> String {
+    match style {
+        Some(style) => format!("{} conflict {}", style, message),
+        None => format!("Conflict {}", message),
+    }
+}
+
+// An open conflict region, from its start marker up to the current line
+struct ConflictRegion {
+    lnum: usize,
+    source: String,
+    // Section markers as (marker character, line number); a section ends before the next one
+    sections: Vec<(u8, usize)>,
+}
+
+impl ConflictRegion {
+    fn has_section(&self, markers: &[u8]) -> bool {
+        self.sections.iter().any(|(m, _)| markers.contains(m))
+    }
+
+    fn unterminated(self, filename: &str, opts: &LintOptions, style: Option<&str>) -> Diagnostic {
+        let first_line = self.source.split_inclusive('\n').next().unwrap_or_default();
+        let trimmed = first_line.trim_end_matches(['\r', '\n']);
+        Diagnostic {
+            file: filename.to_string(),
+            lnum: self.lnum,
+            end_lnum: self.lnum,
+            col: 0,
+            end_col: trimmed.chars().count().saturating_sub(1),
+            severity: opts.severity(ConflictMarker).to_string(),
+            source: first_line.to_string(),
+            source_lnum: self.lnum,
+            code: "conflict-marker".to_string(),
+            message: conflict_message(
+                style,
+                &format!("start marker without an end marker: {}", trimmed),
+            ),
+            helpers: None,
+            fixes: None,
+        }
+    }
+
+    fn close(
+        self,
+        filename: &str,
+        opts: &LintOptions,
+        style: Option<&str>,
+        end_lnum: usize,
+        marker_length: usize,
+    ) -> Diagnostic {
+        let lines: Vec<&str> = self.source.lines().collect();
+        let line_end_col = |lnum: usize| lines[lnum - self.lnum].chars().count().saturating_sub(1);
+
+        let missing = match opts.conflict_marker_style {
+            ConflictMarkerStyle::Git if !self.has_section(b"=") => Some("="),
+            ConflictMarkerStyle::GitDiff3 | ConflictMarkerStyle::JjDiff3
+                if !self.has_section(b"|") =>
+            {
+                Some("|")
+            }
+            ConflictMarkerStyle::GitDiff3 | ConflictMarkerStyle::JjDiff3
+                if !self.has_section(b"=") =>
+            {
+                Some("=")
+            }
+            ConflictMarkerStyle::Jj if !self.has_section(b"%+") => Some("%+"),
+            ConflictMarkerStyle::JjSnapshot if !self.has_section(b"+-") => Some("+-"),
+            ConflictMarkerStyle::Auto | ConflictMarkerStyle::Detect
+                if !self.has_section(b"=%+-") =>
+            {
+                Some("=%+-")
+            }
+            _ => None,
+        };
+        let message = match missing {
+            Some(markers) => conflict_message(
+                style,
+                &format!(
+                    "is missing a {} marker",
+                    markers
+                        .chars()
+                        .map(|c| c.to_string().repeat(marker_length))
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                ),
+            ),
+            None => conflict_message(style, "left unresolved"),
+        };
+
+        // Label every section from its marker up to the line before the next marker
+        let mut helpers = Vec::new();
+        for (i, &(marker, lnum)) in self.sections.iter().enumerate() {
+            let last = self.sections.get(i + 1).map_or(end_lnum, |&(_, next)| next) - 1;
+            let label = match marker {
+                b'<' if last == lnum => continue,
+                b'<' => "ours",
+                b'|' | b'-' => "base",
+                b'=' => "theirs",
+                b'%' => "diff",
+                _ => "side",
+            };
+            helpers.push(Helper {
+                message: label.to_string(),
+                lnum,
+                end_lnum: last,
+                col: 0,
+                end_col: line_end_col(last),
+                source: None,
+            });
+        }
+
+        Diagnostic {
+            file: filename.to_string(),
+            lnum: self.lnum,
+            end_lnum,
+            col: 0,
+            end_col: line_end_col(end_lnum),
+            severity: opts.severity(ConflictMarker).to_string(),
+            source_lnum: self.lnum,
+            source: self.source,
+            code: "conflict-marker".to_string(),
+            message,
+            helpers: Some(helpers),
+            fixes: None,
+        }
+    }
+}
+
+fn escape_bytes(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("\\x{:02X}", b)).collect()
+}
+
+// A technical-debt keyword, with the reference in parentheses after it and the text that follows
+struct TodoMarker<'a> {
+    // Byte range of the keyword and its reference
+    start: usize,
+    end: usize,
+    keyword: &'a str,
+    reference: Option<&'a str>,
+    text: &'a str,
+}
+
+// The first keyword of a line that is a whole word, so that TODO_LIST is not a marker
+fn find_todo<'a>(line: &'a str, keywords: &'a [String]) -> Option<TodoMarker<'a>> {
+    let bytes = line.as_bytes();
+    let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let (start, keyword) = keywords
+        .iter()
+        .filter(|keyword| !keyword.is_empty())
+        .flat_map(|keyword| line.match_indices(keyword.as_str()))
+        .filter(|&(start, keyword)| {
+            let end = start + keyword.len();
+            (start == 0 || !is_word(bytes[start - 1]))
+                && (end == bytes.len() || !is_word(bytes[end]))
+        })
+        .min_by_key(|&(start, _)| start)?;
+
+    let mut end = start + keyword.len();
+    let reference = line[end..]
+        .strip_prefix('(')
+        .and_then(|rest| rest.find(')').map(|close| &rest[..close]));
+    if let Some(reference) = reference {
+        end += reference.len() + 2;
+    }
+    // Drop the separator after the marker and the end of block comments
+    let text = line[end..].trim_start_matches([':', ' ', '\t']).trim_end();
+    let text = text
+        .strip_suffix("*/")
+        .or_else(|| text.strip_suffix("-->"))
+        .unwrap_or(text)
+        .trim_end();
+    Some(TodoMarker {
+        start,
+        end,
+        keyword,
+        reference,
+        text,
+    })
+}
+
+// Patterns of a header template line, where each {year} is either a year or a range of years
+fn header_patterns(template: &str) -> Vec<Pattern> {
+    const YEAR: &str = "[0-9][0-9][0-9][0-9]";
+    let mut parts = template.split("{year}").map(Pattern::escape);
+    let mut sources = vec![parts.next().unwrap_or_default()];
+    for part in parts {
+        sources = sources
+            .iter()
+            .flat_map(|source| {
+                [
+                    format!("{}{}{}", source, YEAR, part),
+                    format!("{}{}-{}{}", source, YEAR, YEAR, part),
+                ]
+            })
+            .collect();
+    }
+    sources
+        .iter()
+        .map(|source| Pattern::new(source).expect("escaped header templates are valid patterns"))
+        .collect()
+}
+
+// Whether a line is a header template line, where only comment syntax such as `//`, `#` or
+// `-->` may surround the template, so that it is not found inside unrelated text
+fn matches_header(patterns: &[Pattern], line: &str) -> bool {
+    let line = line.trim();
+    let first_alnum = line.find(char::is_alphanumeric).unwrap_or(line.len());
+    let last_alnum = line
+        .char_indices()
+        .rfind(|(_, c)| c.is_alphanumeric())
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let boundaries = || line.char_indices().map(|(i, _)| i).chain([line.len()]);
+    boundaries()
+        .take_while(|&start| start <= first_alnum)
+        .any(|start| {
+            boundaries()
+                .filter(|&end| end >= start.max(last_alnum))
+                .any(|end| {
+                    patterns
+                        .iter()
+                        .any(|pattern| pattern.matches(&line[start..end]))
+                })
+        })
+}
+
+// Problems of a shebang line that keep the kernel from running its interpreter
+fn shebang_problem(line: &str, has_bom: bool) -> Option<String> {
+    let command = line
+        .trim_end_matches(['\r', '\n'])
+        .strip_prefix("#!")?
+        .trim();
+    if has_bom {
+        return Some("Shebang after a byte order mark is not recognized".to_string());
+    }
+    if line.ends_with("\r\n") {
+        return Some(
+            "Shebang line ends with CRLF, which is part of the interpreter name".to_string(),
+        );
+    }
+    let mut args = command.split_whitespace();
+    match args.next() {
+        None => Some("Shebang without an interpreter".to_string()),
+        Some(interpreter) if !interpreter.starts_with('/') => Some(format!(
+            "Shebang interpreter '{}' is not an absolute path",
+            interpreter
+        )),
+        Some(interpreter) if interpreter.ends_with("/env") && args.next().is_none() => {
+            Some(format!("Shebang runs {} without a program", interpreter))
+        }
+        Some(_) => None,
+    }
+}
+
+// A line in the normalization form, or None if it already is
+fn normalize(line: &str, form: NormalizationForm) -> Option<String> {
+    let quick_check = match form {
+        NormalizationForm::Nfc => is_nfc_quick(line.chars()),
+        NormalizationForm::Nfd => is_nfd_quick(line.chars()),
+        NormalizationForm::Nfkc => is_nfkc_quick(line.chars()),
+        NormalizationForm::Nfkd => is_nfkd_quick(line.chars()),
+    };
+    if quick_check == IsNormalized::Yes {
+        return None;
+    }
+    let normalized: String = match form {
+        NormalizationForm::Nfc => line.nfc().collect(),
+        NormalizationForm::Nfd => line.nfd().collect(),
+        NormalizationForm::Nfkc => line.nfkc().collect(),
+        NormalizationForm::Nfkd => line.nfkd().collect(),
+    };
+    (normalized != line).then_some(normalized)
+}
+
+// The shortest prefixed token, so that short lines skip the secret scan
+const MIN_SECRET_LINE_LENGTH: usize = 15;
+
+pub fn lint_file_size(
     filename: &str,
-    mut reader: R,
+    file_size: u64,
     runner: &mut LintRunner,
     opts: &LintOptions,
 ) -> bool {
-    // Check for binary content by peeking at the first 8KB, unless in text mode
-    if !opts.text_mode {
-        match reader.fill_buf() {
-            Ok(peeked) if peeked.contains(&0) => {
-                eprintln!(
-                    "Binary file detected in '{}', skipping processing.",
-                    filename
+    if !runner.can_add_issue(opts.severity(MaxFileSize)) {
+        return true;
+    }
+    let diag = file_diagnostic(
+        filename,
+        opts.severity(MaxFileSize),
+        "max-file-size",
+        format!(
+            "Too large file ({}/{} bytes)",
+            file_size, opts.max_file_size
+        ),
+        None,
+    );
+    runner.add_diagnostic(opts, diag)
+}
+
+// Backup and rejected hunk files left by merge tools and patch
+fn merge_artifact_pattern(file_name: &str) -> Option<&'static str> {
+    if file_name.ends_with(".orig") {
+        return Some("*.orig");
+    }
+    if file_name.ends_with(".rej") {
+        return Some("*.rej");
+    }
+    // git mergetool names its temporary files <name>.<KIND>.<pid>.<ext> or <name>_<KIND>_<pid>.<ext>
+    for (pattern, kind) in [
+        ("*.BACKUP.*", "BACKUP"),
+        ("*.BASE.*", "BASE"),
+        ("*.LOCAL.*", "LOCAL"),
+        ("*.REMOTE.*", "REMOTE"),
+    ] {
+        let dotted = format!(".{}.", kind);
+        let underscored = format!("_{}_", kind);
+        let is_match = file_name.find(&dotted).is_some_and(|pos| pos > 0)
+            || file_name.find(&underscored).is_some_and(|pos| {
+                let pid = &file_name[pos + underscored.len()..];
+                let digits = pid.len() - pid.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                pos > 0 && digits > 0
+            });
+        if is_match {
+            return Some(pattern);
+        }
+    }
+    None
+}
+
+pub fn lint_merge_artifact(filename: &str, runner: &mut LintRunner, opts: &LintOptions) -> bool {
+    let Some(pattern) = Path::new(filename)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(merge_artifact_pattern)
+    else {
+        return true;
+    };
+    if !runner.can_add_issue(opts.severity(MergeArtifact)) {
+        return true;
+    }
+    let diag = file_diagnostic(
+        filename,
+        opts.severity(MergeArtifact),
+        "merge-artifact",
+        format!("Leftover merge artifact file ({})", pattern),
+        None,
+    );
+    runner.add_diagnostic(opts, diag)
+}
+
+// Why a path component cannot be checked out on every platform
+fn non_portable_reason(component: &str) -> Option<String> {
+    if let Some(c) = component
+        .chars()
+        .find(|&c| matches!(c, '<' | '>' | ':' | '"' | '|' | '?' | '*') || c.is_control())
+    {
+        return Some(format!(
+            "contains '{}', which is not allowed on Windows",
+            c.escape_default()
+        ));
+    }
+    if component.ends_with(['.', ' ']) {
+        return Some("ends with a dot or space, which is stripped on Windows".to_string());
+    }
+    // Device names are reserved with any extension, like NUL.txt
+    let stem = component.split('.').next().unwrap_or_default().trim_end();
+    let upper = stem.to_ascii_uppercase();
+    let is_reserved = matches!(upper.as_str(), "CON" | "PRN" | "AUX" | "NUL")
+        || (upper.len() == 4
+            && (upper.starts_with("COM") || upper.starts_with("LPT"))
+            && matches!(upper.as_bytes()[3], b'1'..=b'9'));
+    if is_reserved {
+        return Some(format!("is the reserved Windows device name '{}'", stem));
+    }
+    if !is_nfc(component) {
+        return Some("is not in Unicode normalization form C (NFC)".to_string());
+    }
+    None
+}
+
+pub fn lint_paths(filenames: &[String], runner: &mut LintRunner, opts: &LintOptions) -> bool {
+    // Paths and directories by their lowercase form, and the colliding ones already reported
+    let mut first_cased: HashMap<String, String> = HashMap::new();
+    let mut collided: HashSet<String> = HashSet::new();
+    // Non-portable directories are reported once, not for every file below them
+    let mut non_portable: HashSet<String> = HashSet::new();
+    for filename in filenames {
+        let path = Path::new(filename);
+        let mut prefix = PathBuf::new();
+        for component in path.components() {
+            prefix.push(component);
+            let Component::Normal(name) = component else {
+                continue;
+            };
+
+            if !opts.disables.contains(&PortablePath)
+                && runner.can_add_issue(opts.severity(PortablePath))
+                && let Some(reason) = non_portable_reason(&name.to_string_lossy())
+                && non_portable.insert(prefix.to_string_lossy().into_owned())
+            {
+                let diag = file_diagnostic(
+                    filename,
+                    opts.severity(PortablePath),
+                    "portable-path",
+                    format!(
+                        "Path component '{}' {}",
+                        name.to_string_lossy().escape_debug(),
+                        reason
+                    ),
+                    None,
                 );
+                if !runner.add_diagnostic(opts, diag) {
+                    return false;
+                }
+            }
+
+            if !opts.disables.contains(&CaseCollision)
+                && runner.can_add_issue(opts.severity(CaseCollision))
+            {
+                let cased = prefix.to_string_lossy().into_owned();
+                let first = first_cased
+                    .entry(cased.to_lowercase())
+                    .or_insert_with(|| cased.clone());
+                if *first != cased && collided.insert(cased.clone()) {
+                    let helpers = vec![
+                        path_helper(format!("First path: {}", first)),
+                        path_helper(format!("Colliding path: {}", cased)),
+                    ];
+                    let diag = file_diagnostic(
+                        filename,
+                        opts.severity(CaseCollision),
+                        "case-collision",
+                        format!("Path '{}' differs only in case from '{}'", cased, first),
+                        Some(helpers),
+                    );
+                    if !runner.add_diagnostic(opts, diag) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        let length = filename.chars().count();
+        if !opts.disables.contains(&PathLength)
+            && opts.max_path_length > 0
+            && length > opts.max_path_length
+            && runner.can_add_issue(opts.severity(PathLength))
+        {
+            let diag = file_diagnostic(
+                filename,
+                opts.severity(PathLength),
+                "path-length",
+                format!(
+                    "Too long path ({}/{} characters)",
+                    length, opts.max_path_length
+                ),
+                None,
+            );
+            if !runner.add_diagnostic(opts, diag) {
                 return false;
             }
-            Err(e) => {
+        }
+    }
+    true
+}
+
+// Diagnostic about a whole file, without a source to annotate
+fn file_diagnostic(
+    filename: &str,
+    severity: &str,
+    code: &str,
+    message: String,
+    helpers: Option<Vec<Helper>>,
+) -> Diagnostic {
+    Diagnostic {
+        file: filename.to_string(),
+        lnum: 0,
+        end_lnum: 0,
+        col: 0,
+        end_col: 0,
+        severity: severity.to_string(),
+        source: String::new(),
+        source_lnum: 0,
+        code: code.to_string(),
+        message,
+        helpers,
+        fixes: None,
+    }
+}
+
+fn path_helper(message: String) -> Helper {
+    Helper {
+        message,
+        lnum: 0,
+        end_lnum: 0,
+        col: 0,
+        end_col: 0,
+        source: None,
+    }
+}
+
+pub fn lint_lines<R: BufRead>(
+    filename: &str,
+    reader: R,
+    runner: &mut LintRunner,
+    opts: &LintOptions,
+) -> bool {
+    let mut secrets = Vec::new();
+    let result = lint_text(filename, reader, runner, opts, &mut secrets);
+    // Any diagnostic of the file can quote a secret, not only those of the secret rule
+    if !secrets.is_empty() {
+        for diag in runner.diagnostics.iter_mut().filter(|d| d.file == filename) {
+            for secret in &secrets {
+                let masked = mask_secret(secret);
+                diag.source = diag.source.replace(secret.as_str(), &masked);
+                diag.message = diag.message.replace(secret.as_str(), &masked);
+                for helper in diag.helpers.iter_mut().flatten() {
+                    helper.message = helper.message.replace(secret.as_str(), &masked);
+                    if let Some(source) = helper.source.as_mut() {
+                        *source = source.replace(secret.as_str(), &masked);
+                    }
+                }
+            }
+        }
+    }
+    result
+}
+
+fn lint_text<R: BufRead>(
+    filename: &str,
+    mut reader: R,
+    runner: &mut LintRunner,
+    opts: &LintOptions,
+    secrets: &mut Vec<String>,
+) -> bool {
+    let peeked = match reader.fill_buf() {
+        Ok(peeked) => peeked,
+        Err(e) => {
+            eprintln!("Error reading '{}': {}. Skipping.", filename, e);
+            return false;
+        }
+    };
+
+    // UTF-16 and UTF-32 text is only recognized by its byte order mark, and is otherwise left to
+    // the binary file check
+    if let Some(encoding) = UnicodeEncoding::from_bom(peeked)
+        && opts.encoding != EncodingPolicy::Skip
+    {
+        if opts.encoding == EncodingPolicy::Detect {
+            let mut bytes = Vec::new();
+            if let Err(e) = reader.read_to_end(&mut bytes) {
                 eprintln!("Error reading '{}': {}. Skipping.", filename, e);
                 return false;
             }
-            _ => {}
+            let decoded = encoding.decode(&bytes);
+            return lint_text(
+                filename,
+                Cursor::new(decoded.into_bytes()),
+                runner,
+                opts,
+                secrets,
+            );
+        }
+        if !opts.disables.contains(&Encoding) {
+            if runner.can_add_issue(opts.severity(Encoding)) {
+                let diag = Diagnostic {
+                    file: filename.to_string(),
+                    lnum: 0,
+                    end_lnum: 0,
+                    col: 0,
+                    end_col: 0,
+                    severity: opts.severity(Encoding).to_string(),
+                    source: String::new(),
+                    source_lnum: 0,
+                    code: "encoding".to_string(),
+                    message: format!(
+                        "File is encoded as {}, expected UTF-8 (use --encoding detect to decode it)",
+                        encoding.name()
+                    ),
+                    helpers: None,
+                    fixes: None,
+                };
+                return runner.add_diagnostic(opts, diag);
+            }
+            return true;
         }
     }
 
+    // Check for binary content by peeking at the first 8KB, unless in text mode
+    if !opts.text_mode && peeked.contains(&0) {
+        eprintln!(
+            "Binary file detected in '{}', skipping processing.",
+            filename
+        );
+        return false;
+    }
+
+    let has_bom = peeked.starts_with(UTF8_BOM);
+    let bom_issue = match opts.bom {
+        BomPolicy::Forbid if has_bom => Some(("File starts with a UTF-8 byte order mark", false)),
+        BomPolicy::Require if !has_bom && !peeked.is_empty() => {
+            Some(("File is missing a UTF-8 byte order mark", true))
+        }
+        _ => None,
+    };
+    if let Some((message, bom)) = bom_issue
+        && !opts.disables.contains(&Bom)
+        && runner.can_add_issue(opts.severity(Bom))
+    {
+        let diag = Diagnostic {
+            file: filename.to_string(),
+            lnum: 0,
+            end_lnum: 0,
+            col: 0,
+            end_col: 0,
+            severity: opts.severity(Bom).to_string(),
+            source: String::new(),
+            source_lnum: 0,
+            code: "bom".to_string(),
+            message: message.to_string(),
+            helpers: None,
+            fixes: Some(vec![Fix {
+                lnum: 0,
+                end_lnum: 0,
+                replacement: String::new(),
+                bom: Some(bom),
+            }]),
+        };
+        if !runner.add_diagnostic(opts, diag) {
+            return false;
+        }
+    }
+    // The BOM is not part of the first line, so it never counts toward its columns or width
+    if has_bom {
+        reader.consume(UTF8_BOM.len());
+    }
+
+    let mut bytes = Vec::with_capacity(1024);
     let mut buffer = String::with_capacity(1024);
+    let mut byte_offset = if has_bom { UTF8_BOM.len() } else { 0 };
     let mut non_blank_lnum: isize = -1;
     let mut non_blank_line = String::new();
     let mut line_idx = 0;
     let mut trailing_blank_count: usize = 0;
 
+    // Blank or whitespace-only lines since the last line with content, for blank-at-eof
+    let mut eof_blank_lnum: Option<usize> = None;
+    let mut eof_blank_lines = String::new();
+    // Last line with content and the empty lines after it, for the final newline policy
+    let mut final_content_lnum: Option<usize> = None;
+    let mut final_lines = String::new();
+
     // Store data for the final newline check: (lnum, col, raw_line, ends_with_eol)
     let mut last_line_data: Option<(usize, usize, String, bool)> = None;
 
     // Conflict marker detection
+    // Detection needs a repository, so a style left undetected recognizes every style like auto
     let is_jj_style = matches!(
         opts.conflict_marker_style,
-        ConflictMarkerStyle::Jj | ConflictMarkerStyle::JjDiff3 | ConflictMarkerStyle::JjSnapshot
+        ConflictMarkerStyle::Jj
+            | ConflictMarkerStyle::JjDiff3
+            | ConflictMarkerStyle::JjSnapshot
+            | ConflictMarkerStyle::Auto
+            | ConflictMarkerStyle::Detect
     );
     let mut current_min_length = 7;
+    let conflict_style = match opts.conflict_marker_style {
+        ConflictMarkerStyle::Git => Some("Git"),
+        ConflictMarkerStyle::GitDiff3 => Some("Git diff3"),
+        ConflictMarkerStyle::Jj => Some("Jujutsu"),
+        ConflictMarkerStyle::JjDiff3 => Some("Jujutsu diff3"),
+        ConflictMarkerStyle::JjSnapshot => Some("Jujutsu snapshot"),
+        ConflictMarkerStyle::Auto | ConflictMarkerStyle::Detect => None,
+    };
+    let mut conflict: Option<ConflictRegion> = None;
+    // Separators outside a conflict are usually heading underlines or horizontal rules
+    let report_orphan_separators = opts.strict_conflict_markers && !is_markup_file(filename);
+
+    // Line ending detection: the expected ending, where it was first seen, and offending lines
+    let mut expected_eol = match opts.eol_style {
+        EolStyle::Lf => Some(LineEnding::Lf),
+        EolStyle::Crlf => Some(LineEnding::Crlf),
+        EolStyle::Consistent => None,
+    };
+    let mut first_eol: Option<Helper> = None;
+    let mut eol_reported = 0;
+    let mut eol_unreported: Option<Diagnostic> = None;
+    let mut eol_unreported_count = 0;
+
+    // Indented lines as (lnum, indented with tabs), to find the dominant indentation. Only the
+    // first raw lines of each style, indexed by whether it uses tabs, are kept for the report,
+    // as many as can still be reported.
+    let mut indented_lines: Vec<(usize, bool)> = Vec::new();
+    let mut indented_sources: [Vec<String>; 2] = Default::default();
+    let indented_sources_limit = runner
+        .remaining_issues(opts, opts.severity(Indent))
+        .map_or(usize::MAX, |remaining| remaining.max(1));
+
+    // Blank and whitespace-only lines before the first line with content
+    let mut first_content_lnum: Option<usize> = None;
+    let mut leading_lines = String::new();
+
+    // The first line past the maximum number of lines
+    let mut first_excess_line: Option<String> = None;
+
+    // Header detection: template lines found in order, the first line, and where a header goes
+    let header_patterns: Vec<Vec<Pattern>> = if opts.disables.contains(&Header) {
+        Vec::new()
+    } else {
+        opts.header
+            .iter()
+            .map(|line| header_patterns(line))
+            .collect()
+    };
+    let mut header_found = 0;
+    let mut first_line = String::new();
+    let mut header_insert_lnum = 0;
+    let mut header_insert: Option<(bool, String)> = None;
 
     loop {
-        buffer.clear();
-        match reader.read_line(&mut buffer) {
+        bytes.clear();
+        match reader.read_until(b'\n', &mut bytes) {
             Ok(0) => break, // EOF
-            Ok(_) => {
+            Ok(read) => {
                 let lnum = line_idx;
                 line_idx += 1;
+                let line_offset = byte_offset;
+                byte_offset += read;
+
+                // Invalid UTF-8 is reported and replaced so that the rest of the file is linted
+                buffer.clear();
+                buffer.push_str(&String::from_utf8_lossy(&bytes));
                 let line = &buffer;
+                if let Err(e) = std::str::from_utf8(&bytes)
+                    && !opts.disables.contains(&Encoding)
+                    && runner.can_add_issue(opts.severity(Encoding))
+                {
+                    let valid = e.valid_up_to();
+                    let invalid_len = e.error_len().unwrap_or(bytes.len() - valid);
+                    let col = String::from_utf8_lossy(&bytes[..valid]).chars().count();
+                    let diag = Diagnostic {
+                        file: filename.to_string(),
+                        lnum,
+                        end_lnum: lnum,
+                        col,
+                        end_col: col,
+                        severity: opts.severity(Encoding).to_string(),
+                        source: line.to_string(),
+                        source_lnum: lnum,
+                        code: "encoding".to_string(),
+                        message: format!(
+                            "Invalid UTF-8 sequence {} at byte offset {}",
+                            escape_bytes(&bytes[valid..valid + invalid_len]),
+                            line_offset + valid
+                        ),
+                        helpers: None,
+                        fixes: None,
+                    };
+                    if !runner.add_diagnostic(opts, diag) {
+                        return false;
+                    }
+                }
+
+                if opts.max_lines > 0 && lnum == opts.max_lines {
+                    first_excess_line = Some(line.to_string());
+                }
 
                 let ends_with_eol = line.ends_with('\n') || line.ends_with('\r');
                 let trimmed = line.trim_end_matches(['\r', '\n']);
 
+                // Rust inner attributes like #![allow(...)] are not shebangs
+                if lnum == 0 && !opts.disables.contains(&Shebang) {
+                    let has_shebang = trimmed.starts_with("#!") && !trimmed.starts_with("#![");
+                    let problems = [
+                        if has_shebang {
+                            shebang_problem(line, has_bom)
+                        } else {
+                            None
+                        },
+                        match (has_shebang, opts.executable) {
+                            (true, Some(false)) => {
+                                Some("Script with a shebang is not executable".to_string())
+                            }
+                            (false, Some(true)) => {
+                                Some("Executable file without a shebang".to_string())
+                            }
+                            _ => None,
+                        },
+                    ];
+                    for message in problems.into_iter().flatten() {
+                        if !runner.can_add_issue(opts.severity(Shebang)) {
+                            break;
+                        }
+                        let diag = Diagnostic {
+                            file: filename.to_string(),
+                            lnum,
+                            end_lnum: lnum,
+                            col: 0,
+                            end_col: trimmed.chars().count().saturating_sub(1),
+                            severity: opts.severity(Shebang).to_string(),
+                            source: line.to_string(),
+                            source_lnum: lnum,
+                            code: "shebang".to_string(),
+                            message,
+                            helpers: None,
+                            fixes: None,
+                        };
+                        if !runner.add_diagnostic(opts, diag) {
+                            return false;
+                        }
+                    }
+                }
+
+                if !header_patterns.is_empty() {
+                    if lnum == 0 {
+                        first_line = line.to_string();
+                        // A header goes after a shebang, which has to stay on the first line
+                        if trimmed.starts_with("#!") {
+                            header_insert_lnum = 1;
+                        }
+                    }
+                    if lnum == header_insert_lnum {
+                        let eol = &line[trimmed.len()..];
+                        header_insert = Some((
+                            trimmed.trim().is_empty(),
+                            if eol.is_empty() { "\n" } else { eol }.to_string(),
+                        ));
+                    }
+                    if lnum < opts.header_lines
+                        && header_found < header_patterns.len()
+                        && matches_header(&header_patterns[header_found], trimmed)
+                    {
+                        header_found += 1;
+                    }
+                }
+
                 if !opts.disables.contains(&MixIndent)
-                    && runner.can_add_issue("warning")
+                    && runner.can_add_issue(opts.severity(MixIndent))
                     && let Some(space_col) = trimmed.chars().position(|c| c == ' ')
                     && let Some(tab_col) = trimmed.chars().position(|c| c == '\t')
                     && {
@@ -83,6 +1038,7 @@ pub fn lint_lines<R: BufRead>(
                         } else {
                             space_col - 1
                         },
+                        source: None,
                     };
                     let diag = Diagnostic {
                         file: filename.to_string(),
@@ -90,23 +1046,232 @@ pub fn lint_lines<R: BufRead>(
                         end_lnum: lnum,
                         col: if space_col == 0 { tab_col } else { space_col },
                         end_col: if space_col == 0 { tab_col } else { space_col },
-                        severity: "warning".to_string(),
+                        severity: opts.severity(MixIndent).to_string(),
                         source: line.to_string(),
                         source_lnum: lnum,
                         code: "mix-indent".to_string(),
                         message: "Mixed tabs and whitespaces".to_string(),
                         helpers: Some(vec![helper]),
+                        fixes: None,
+                    };
+                    if !runner.add_diagnostic(opts, diag) {
+                        return false;
+                    }
+                }
+
+                if !opts.disables.contains(&Indent) && runner.can_add_issue(opts.severity(Indent)) {
+                    let content = trimmed.trim_start_matches([' ', '\t']);
+                    let indent_len = trimmed.len() - content.len();
+                    if !content.is_empty() && indent_len > 0 {
+                        // Spaces after leading tabs are alignment, not indentation
+                        let uses_tabs = trimmed.starts_with('\t');
+                        let has_tabs = trimmed[..indent_len].contains('\t');
+                        // (message, whether converting the indentation characters fixes it)
+                        // Indents narrower than one level, like the ` * ` of block comment
+                        // continuations, are alignment and tell nothing about the style of a file
+                        let narrow = !uses_tabs && indent_len < opts.indent_width;
+                        let problem = match opts.indent_style {
+                            IndentStyle::Consistent => {
+                                if !narrow {
+                                    indented_lines.push((lnum, uses_tabs));
+                                    let sources = &mut indented_sources[uses_tabs as usize];
+                                    if sources.len() < indented_sources_limit {
+                                        sources.push(line.to_string());
+                                    }
+                                }
+                                None
+                            }
+                            IndentStyle::Tabs if !uses_tabs && !narrow => {
+                                Some(("Indentation uses spaces, expected tabs".to_string(), true))
+                            }
+                            IndentStyle::Spaces if has_tabs => {
+                                Some(("Indentation uses tabs, expected spaces".to_string(), true))
+                            }
+                            IndentStyle::Spaces
+                                if !indent_len.is_multiple_of(opts.indent_width) =>
+                            {
+                                Some((
+                                    format!(
+                                        "Indentation of {} spaces is not a multiple of {}",
+                                        indent_len, opts.indent_width
+                                    ),
+                                    false,
+                                ))
+                            }
+                            _ => None,
+                        };
+                        if let Some((message, convertible)) = problem {
+                            // Only a wrong indentation character can be converted, not a width,
+                            // and indents narrower than a tab stay as they are
+                            let replacement = convert_indentation(
+                                line,
+                                opts.indent_style == IndentStyle::Tabs,
+                                opts.indent_width,
+                            );
+                            let fixes = (convertible && replacement != *line).then(|| {
+                                vec![Fix {
+                                    lnum,
+                                    end_lnum: lnum + 1,
+                                    replacement,
+                                    bom: None,
+                                }]
+                            });
+                            let diag = Diagnostic {
+                                file: filename.to_string(),
+                                lnum,
+                                end_lnum: lnum,
+                                col: 0,
+                                end_col: indent_len - 1,
+                                severity: opts.severity(Indent).to_string(),
+                                source: line.to_string(),
+                                source_lnum: lnum,
+                                code: "indent".to_string(),
+                                message,
+                                helpers: None,
+                                fixes,
+                            };
+                            if !runner.add_diagnostic(opts, diag) {
+                                return false;
+                            }
+                        }
+                    }
+                }
+
+                // Indentation classes of Git's core.whitespace, checked on lines with content
+                let content = trimmed.trim_start_matches([' ', '\t']);
+                if !content.is_empty() && content.len() < trimmed.len() {
+                    let indent = &trimmed[..trimmed.len() - content.len()];
+                    let last_tab = indent.rfind('\t');
+                    let spaces_after_tab = indent.len() - last_tab.map_or(0, |tab| tab + 1);
+                    let width = opts.whitespace.tab_width.unwrap_or(opts.tab_width);
+                    let problems = [
+                        last_tab
+                            .filter(|_| opts.whitespace.space_before_tab)
+                            .and_then(|tab| Some((indent[..tab].find(' ')?, tab)))
+                            .map(|(col, end_col)| {
+                                (
+                                    SpaceBeforeTab,
+                                    col,
+                                    end_col,
+                                    "Space before tab in indent",
+                                    true,
+                                )
+                            }),
+                        (opts.whitespace.indent_with_non_tab && spaces_after_tab >= width).then(
+                            || {
+                                (
+                                    IndentWithNonTab,
+                                    indent.len() - spaces_after_tab,
+                                    indent.len() - 1,
+                                    "Indent uses spaces instead of tabs",
+                                    true,
+                                )
+                            },
+                        ),
+                        indent
+                            .find('\t')
+                            .filter(|_| opts.whitespace.tab_in_indent)
+                            .map(|col| {
+                                (TabInIndent, col, indent.len() - 1, "Tab in indent", false)
+                            }),
+                    ];
+                    for (check, col, end_col, message, to_tabs) in problems.into_iter().flatten() {
+                        if opts.disables.contains(&check)
+                            || !runner.can_add_issue(opts.severity(check))
+                        {
+                            continue;
+                        }
+                        let diag = Diagnostic {
+                            file: filename.to_string(),
+                            lnum,
+                            end_lnum: lnum,
+                            col,
+                            end_col,
+                            severity: opts.severity(check).to_string(),
+                            source: line.to_string(),
+                            source_lnum: lnum,
+                            code: match check {
+                                SpaceBeforeTab => "space-before-tab",
+                                IndentWithNonTab => "indent-with-non-tab",
+                                _ => "tab-in-indent",
+                            }
+                            .to_string(),
+                            message: message.to_string(),
+                            helpers: None,
+                            fixes: Some(convert_indentation(line, to_tabs, width))
+                                .filter(|replacement| replacement != line)
+                                .map(|replacement| {
+                                    vec![Fix {
+                                        lnum,
+                                        end_lnum: lnum + 1,
+                                        replacement,
+                                        bom: None,
+                                    }]
+                                }),
+                        };
+                        if !runner.add_diagnostic(opts, diag) {
+                            return false;
+                        }
+                    }
+                }
+
+                if trimmed.trim_start_matches([' ', '\t']).is_empty() {
+                    eof_blank_lnum.get_or_insert(lnum);
+                    eof_blank_lines.push_str(line);
+                } else {
+                    eof_blank_lnum = None;
+                    eof_blank_lines.clear();
+                }
+                if !trimmed.trim_start_matches([' ', '\t']).is_empty() {
+                    final_content_lnum = Some(lnum);
+                    final_lines.clear();
+                }
+                final_lines.push_str(line);
+
+                let whitespace_only = !trimmed.is_empty()
+                    && trimmed.trim_start_matches([' ', '\t']).is_empty()
+                    && !opts.disables.contains(&WhitespaceOnlyLine);
+                if whitespace_only && runner.can_add_issue(opts.severity(WhitespaceOnlyLine)) {
+                    let diag = Diagnostic {
+                        file: filename.to_string(),
+                        lnum,
+                        end_lnum: lnum,
+                        col: 0,
+                        end_col: trimmed.chars().count() - 1,
+                        severity: opts.severity(WhitespaceOnlyLine).to_string(),
+                        source: line.to_string(),
+                        source_lnum: lnum,
+                        code: "whitespace-only-line".to_string(),
+                        message: "Line contains only whitespaces or tabs".to_string(),
+                        helpers: None,
+                        fixes: Some(vec![Fix {
+                            lnum,
+                            end_lnum: lnum + 1,
+                            replacement: line[trimmed.len()..].to_string(),
+                            bom: None,
+                        }]),
                     };
                     if !runner.add_diagnostic(opts, diag) {
                         return false;
                     }
                 }
 
-                if !opts.disables.contains(&TrailingSpace) && runner.can_add_issue("warning") {
-                    let trimmed_trailing_space = trimmed.trim_end_matches([' ', '\t']);
-                    if trimmed.len() > trimmed_trailing_space.len() {
+                // Whitespace-only lines are reported by their own rule unless it is disabled
+                if !opts.disables.contains(&TrailingSpace)
+                    && opts.whitespace.blank_at_eol
+                    && !whitespace_only
+                    && runner.can_add_issue(opts.severity(TrailingSpace))
+                {
+                    // Without cr-at-eol, the carriage return of a CRLF is trailing whitespace
+                    let body = if opts.whitespace.cr_at_eol {
+                        trimmed
+                    } else {
+                        line.trim_end_matches('\n')
+                    };
+                    let trimmed_trailing_space = body.trim_end_matches([' ', '\t', '\r']);
+                    if body.len() > trimmed_trailing_space.len() {
                         let col = trimmed_trailing_space.chars().count();
-                        let end_col = trimmed.chars().count() - 1;
+                        let end_col = body.chars().count() - 1;
 
                         let diag = Diagnostic {
                             file: filename.to_string(),
@@ -114,12 +1279,13 @@ pub fn lint_lines<R: BufRead>(
                             end_lnum: lnum,
                             col,
                             end_col,
-                            severity: "warning".to_string(),
+                            severity: opts.severity(TrailingSpace).to_string(),
                             source: line.to_string(),
                             source_lnum: lnum,
                             code: "trailing-space".to_string(),
                             message: "Trailing whitespaces or tabs".to_string(),
                             helpers: None,
+                            fixes: None,
                         };
                         if !runner.add_diagnostic(opts, diag) {
                             return false;
@@ -127,6 +1293,303 @@ pub fn lint_lines<R: BufRead>(
                     }
                 }
 
+                if !opts.disables.contains(&Eol) && runner.can_add_issue(opts.severity(Eol)) {
+                    let endings = line_endings(line);
+                    if expected_eol.is_none()
+                        && let Some(&(col, ending)) = endings.first()
+                    {
+                        expected_eol = Some(ending);
+                        first_eol = Some(Helper {
+                            message: format!("First line ending is {}", ending.name()),
+                            lnum,
+                            end_lnum: lnum,
+                            col,
+                            end_col: col + ending.as_str().len() - 1,
+                            source: Some(line.to_string()),
+                        });
+                    }
+                    let expected = expected_eol.unwrap_or(LineEnding::Lf);
+                    if let Some(&(col, ending)) = endings.iter().find(|(_, e)| *e != expected) {
+                        let end_col = col + ending.as_str().len() - 1;
+                        let fix = Fix {
+                            lnum,
+                            end_lnum: lnum + 1,
+                            replacement: convert_line_endings(line, expected),
+                            bom: None,
+                        };
+                        if first_eol.is_none() {
+                            first_eol = Some(Helper {
+                                message: "First offending line ending".to_string(),
+                                lnum,
+                                end_lnum: lnum,
+                                col,
+                                end_col,
+                                source: Some(line.to_string()),
+                            });
+                        }
+                        let diag = Diagnostic {
+                            file: filename.to_string(),
+                            lnum,
+                            end_lnum: lnum,
+                            col,
+                            end_col,
+                            severity: opts.severity(Eol).to_string(),
+                            source: line.to_string(),
+                            source_lnum: lnum,
+                            code: "eol".to_string(),
+                            message: format!(
+                                "Line ending is {}, expected {}",
+                                ending.name(),
+                                expected.name()
+                            ),
+                            helpers: if opts.eol_style == EolStyle::Consistent {
+                                first_eol.clone().map(|helper| vec![helper])
+                            } else {
+                                None
+                            },
+                            fixes: Some(vec![fix]),
+                        };
+                        if eol_reported < EOL_REPORT_LIMIT {
+                            eol_reported += 1;
+                            if !runner.add_diagnostic(opts, diag) {
+                                return false;
+                            }
+                        } else {
+                            eol_unreported_count += 1;
+                            let fix = diag.fixes.unwrap().remove(0);
+                            eol_unreported
+                                .get_or_insert(Diagnostic {
+                                    fixes: Some(Vec::new()),
+                                    ..diag
+                                })
+                                .fixes
+                                .get_or_insert_default()
+                                .push(fix);
+                        }
+                    }
+                }
+
+                // Bidi controls, invisible characters and confusables are non-ASCII, so plain ASCII
+                // lines are only scanned when they contain control characters other than tabs
+                if !trimmed.is_ascii()
+                    || trimmed.bytes().any(|b| b.is_ascii_control() && b != b'\t')
+                {
+                    let chars: Vec<char> = trimmed.chars().collect();
+                    for (col, &c) in chars.iter().enumerate() {
+                        let (check, message) = if let Some(name) = control_char_name(c) {
+                            if opts.allowed_control_chars.contains(&c) {
+                                continue;
+                            }
+                            (
+                                ControlChar,
+                                format!("Control character U+{:04X} ({})", c as u32, name),
+                            )
+                        } else if let Some(name) = bidi_control_name(c) {
+                            (
+                                BidiChar,
+                                format!(
+                                    "Bidirectional control character U+{:04X} ({})",
+                                    c as u32, name
+                                ),
+                            )
+                        } else if let Some(name) = invisible_char_name(c) {
+                            // Joiners are legitimate between non-ASCII characters (emoji, Indic
+                            // and Arabic scripts), but never next to ASCII text
+                            if matches!(c, '\u{200C}' | '\u{200D}')
+                                && col > 0
+                                && !chars[col - 1].is_ascii()
+                                && chars.get(col + 1).is_some_and(|next| !next.is_ascii())
+                            {
+                                continue;
+                            }
+                            (
+                                InvisibleChar,
+                                format!("Invisible character U+{:04X} ({})", c as u32, name),
+                            )
+                        } else if let Some((name, lookalike)) = confusable_char(c) {
+                            // Cyrillic and Greek letters are only suspicious in words that mix
+                            // them with Latin letters, not in text written in those scripts
+                            if c.is_alphabetic() && matches!(c, '\u{0370}'..='\u{04FF}') {
+                                let is_word = |c: &char| c.is_alphanumeric() || *c == '_';
+                                let start = chars[..col]
+                                    .iter()
+                                    .rposition(|c| !is_word(c))
+                                    .map_or(0, |pos| pos + 1);
+                                let end = chars[col..]
+                                    .iter()
+                                    .position(|c| !is_word(c))
+                                    .map_or(chars.len(), |pos| col + pos);
+                                if !chars[start..end].iter().any(char::is_ascii_alphabetic) {
+                                    continue;
+                                }
+                            }
+                            (
+                                Confusable,
+                                format!(
+                                    "Confusable character U+{:04X} ({}) looks like '{}'",
+                                    c as u32, name, lookalike
+                                ),
+                            )
+                        } else {
+                            continue;
+                        };
+                        if opts.disables.contains(&check)
+                            || !runner.can_add_issue(opts.severity(check))
+                        {
+                            continue;
+                        }
+                        let diag = Diagnostic {
+                            file: filename.to_string(),
+                            lnum,
+                            end_lnum: lnum,
+                            col,
+                            end_col: col,
+                            severity: opts.severity(check).to_string(),
+                            source: line.to_string(),
+                            source_lnum: lnum,
+                            code: match check {
+                                BidiChar => "bidi-char",
+                                ControlChar => "control-char",
+                                Confusable => "confusable",
+                                _ => "invisible-char",
+                            }
+                            .to_string(),
+                            message,
+                            helpers: None,
+                            fixes: None,
+                        };
+                        if !runner.add_diagnostic(opts, diag) {
+                            return false;
+                        }
+                    }
+                }
+
+                if !opts.disables.contains(&Normalization)
+                    && !trimmed.is_ascii()
+                    && runner.can_add_issue(opts.severity(Normalization))
+                    && let Some(normalized) = normalize(trimmed, opts.normalization_form)
+                {
+                    // Report the characters between the common prefix and suffix
+                    let chars: Vec<char> = trimmed.chars().collect();
+                    let normalized_chars: Vec<char> = normalized.chars().collect();
+                    let prefix = chars
+                        .iter()
+                        .zip(&normalized_chars)
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    let suffix = chars[prefix..]
+                        .iter()
+                        .rev()
+                        .zip(normalized_chars[prefix..].iter().rev())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    let diag = Diagnostic {
+                        file: filename.to_string(),
+                        lnum,
+                        end_lnum: lnum,
+                        col: prefix,
+                        end_col: max(prefix, (chars.len() - suffix).saturating_sub(1)),
+                        severity: opts.severity(Normalization).to_string(),
+                        source: line.to_string(),
+                        source_lnum: lnum,
+                        code: "normalization".to_string(),
+                        message: format!(
+                            "Line is not in Unicode normalization form {}",
+                            opts.normalization_form.as_str()
+                        ),
+                        helpers: None,
+                        fixes: Some(vec![Fix {
+                            lnum,
+                            end_lnum: lnum + 1,
+                            replacement: normalized + &line[trimmed.len()..],
+                            bom: None,
+                        }]),
+                    };
+                    if !runner.add_diagnostic(opts, diag) {
+                        return false;
+                    }
+                }
+
+                // Secrets are collected even past the limit so that every diagnostic masks them
+                if !opts.disables.contains(&Secret) && trimmed.len() >= MIN_SECRET_LINE_LENGTH {
+                    for secret in find_secrets(trimmed) {
+                        let value = &trimmed[secret.start..secret.end];
+                        if opts
+                            .secret_allowlist
+                            .iter()
+                            .any(|pattern| pattern.matches(value))
+                        {
+                            continue;
+                        }
+                        let shown = if secret.kind == PRIVATE_KEY {
+                            value.to_string()
+                        } else {
+                            secrets.push(value.to_string());
+                            mask_secret(value)
+                        };
+                        if !runner.can_add_issue(opts.severity(Secret)) {
+                            continue;
+                        }
+                        let col = trimmed[..secret.start].chars().count();
+                        let diag = Diagnostic {
+                            file: filename.to_string(),
+                            lnum,
+                            end_lnum: lnum,
+                            col,
+                            end_col: col + value.chars().count() - 1,
+                            severity: opts.severity(Secret).to_string(),
+                            source: line.to_string(),
+                            source_lnum: lnum,
+                            code: "secret".to_string(),
+                            message: format!("{}: {}", secret.kind, shown),
+                            helpers: None,
+                            fixes: None,
+                        };
+                        if !runner.add_diagnostic(opts, diag) {
+                            return false;
+                        }
+                    }
+                }
+
+                if !opts.disables.contains(&Todo)
+                    && runner.can_add_issue(opts.severity(Todo))
+                    && let Some(marker) = find_todo(trimmed, &opts.todo_keywords)
+                {
+                    let mut message = marker.keyword.to_string();
+                    if let Some(reference) = marker.reference {
+                        message.push_str(&format!("({})", reference));
+                    }
+                    if let Some(pattern) = &opts.todo_issue_pattern
+                        && !marker.reference.is_some_and(|r| pattern.matches(r))
+                    {
+                        message.push_str(&format!(
+                            " without an issue reference matching '{}'",
+                            pattern
+                        ));
+                    }
+                    if !marker.text.is_empty() {
+                        message.push_str(&format!(": {}", marker.text));
+                    }
+                    let col = trimmed[..marker.start].chars().count();
+                    let diag = Diagnostic {
+                        file: filename.to_string(),
+                        lnum,
+                        end_lnum: lnum,
+                        col,
+                        end_col: col + trimmed[marker.start..marker.end].chars().count() - 1,
+                        severity: opts.severity(Todo).to_string(),
+                        source: line.to_string(),
+                        source_lnum: lnum,
+                        code: "todo".to_string(),
+                        message,
+                        helpers: None,
+                        fixes: None,
+                    };
+                    if !runner.add_diagnostic(opts, diag) {
+                        return false;
+                    }
+                }
+
                 if !opts.disables.contains(&ConflictMarker) {
                     let chars = match opts.conflict_marker_style {
                         ConflictMarkerStyle::Git => "<>=".as_bytes(),
@@ -135,12 +1598,15 @@ pub fn lint_lines<R: BufRead>(
                             "<>=|".as_bytes()
                         }
                         ConflictMarkerStyle::JjSnapshot => "<+->".as_bytes(),
+                        ConflictMarkerStyle::Auto | ConflictMarkerStyle::Detect => {
+                            "<>=|%+\\-".as_bytes()
+                        }
                     };
-                    let mut found_marker = false;
+                    let mut found_marker = None;
                     let mut marker_length = 0;
 
-                    // Helper function to check if a marker matches for a given length
-                    let check_marker_for_length = |length: usize| -> bool {
+                    // Helper function to find the marker matching for a given length
+                    let check_marker_for_length = |length: usize| -> Option<u8> {
                         for &c in chars {
                             let is_match = if trimmed.len() == length {
                                 trimmed.as_bytes().iter().all(|&b| b == c)
@@ -152,17 +1618,21 @@ pub fn lint_lines<R: BufRead>(
                                 false
                             };
                             if is_match {
-                                return true;
+                                return Some(c);
                             }
                         }
-                        false
+                        None
                     };
 
-                    // For JJ styles, check for longer markers first
+                    // For JJ styles, check for longer markers first. Jujutsu lengthens the markers
+                    // of a whole file, so only a start marker can raise the length; a longer
+                    // separator is more likely a heading underline or a horizontal rule.
                     if is_jj_style {
                         for len in (11..=trimmed.len()).step_by(4) {
-                            if check_marker_for_length(len) {
-                                found_marker = true;
+                            if let Some(c) = check_marker_for_length(len)
+                                && c == b'<'
+                            {
+                                found_marker = Some(c);
                                 marker_length = len;
                                 break;
                             }
@@ -170,68 +1640,114 @@ pub fn lint_lines<R: BufRead>(
                     }
 
                     // If not found longer, check for markers of current_min_length
-                    if !found_marker && check_marker_for_length(current_min_length) {
-                        found_marker = true;
+                    if found_marker.is_none() {
+                        found_marker = check_marker_for_length(current_min_length);
                         marker_length = current_min_length;
                     }
 
-                    if found_marker {
+                    if let Some(marker) = found_marker {
                         // For JJ styles, update min length if longer marker found
                         if is_jj_style
                             && marker_length > current_min_length
                             && marker_length % 4 == 3
                         {
-                            runner.clear_conflict_markers();
+                            runner.clear_conflict_markers(filename, opts.severity(ConflictMarker));
                             current_min_length = marker_length;
+                            conflict = None;
                         }
 
-                        // Check if can add issue
-                        if !runner.can_add_issue("error") {
-                            if !is_jj_style {
-                                return false; // Early terminate for non-JJ styles
+                        let end_col = trimmed.chars().count().saturating_sub(1);
+                        let diag = match (marker, conflict.take()) {
+                            (b'<', previous) => {
+                                conflict = Some(ConflictRegion {
+                                    lnum,
+                                    source: line.to_string(),
+                                    sections: vec![(marker, lnum)],
+                                });
+                                previous.map(|region| {
+                                    region.unterminated(filename, opts, conflict_style)
+                                })
                             }
-                            // For JJ styles, continue processing
-                        } else {
-                            let style_name = match opts.conflict_marker_style {
-                                ConflictMarkerStyle::Git => "Git",
-                                ConflictMarkerStyle::GitDiff3 => "Git diff3",
-                                ConflictMarkerStyle::Jj => "Jujutsu",
-                                ConflictMarkerStyle::JjDiff3 => "Jujutsu diff3",
-                                ConflictMarkerStyle::JjSnapshot => "Jujutsu snapshot",
-                            };
-                            let diag = Diagnostic {
-                                file: filename.to_string(),
-                                lnum,
-                                end_lnum: lnum,
-                                col: 0,
-                                end_col: trimmed.chars().count().saturating_sub(1),
-                                severity: "error".to_string(),
-                                source: line.to_string(),
-                                source_lnum: lnum,
-                                code: "conflict-marker".to_string(),
-                                message: format!("{} conflict marker: {}", style_name, trimmed),
-                                helpers: None,
-                            };
-                            if !runner.add_diagnostic(opts, diag) {
+                            (b'>', Some(mut region)) => {
+                                region.source.push_str(line);
+                                Some(region.close(
+                                    filename,
+                                    opts,
+                                    conflict_style,
+                                    lnum,
+                                    current_min_length,
+                                ))
+                            }
+                            (_, Some(mut region)) => {
+                                region.source.push_str(line);
+                                // Jujutsu diff sections continue on a \\\\\\\ marker
+                                if marker != b'\\' {
+                                    region.sections.push((marker, lnum));
+                                }
+                                conflict = Some(region);
+                                None
+                            }
+                            (_, None) if marker == b'>' || report_orphan_separators => {
+                                Some(Diagnostic {
+                                    file: filename.to_string(),
+                                    lnum,
+                                    end_lnum: lnum,
+                                    col: 0,
+                                    end_col,
+                                    severity: opts.severity(ConflictMarker).to_string(),
+                                    source: line.to_string(),
+                                    source_lnum: lnum,
+                                    code: "conflict-marker".to_string(),
+                                    message: conflict_message(
+                                        conflict_style,
+                                        &if marker == b'>' {
+                                            format!(
+                                                "end marker without a start marker: {}",
+                                                trimmed
+                                            )
+                                        } else {
+                                            format!("marker outside a conflict: {}", trimmed)
+                                        },
+                                    ),
+                                    helpers: None,
+                                    fixes: None,
+                                })
+                            }
+                            (_, None) => None,
+                        };
+
+                        if let Some(diag) = diag {
+                            // Check if can add issue
+                            if !runner.can_add_issue(opts.severity(ConflictMarker)) {
+                                if !is_jj_style && opts.severity(ConflictMarker) == "error" {
+                                    return false; // Early terminate for non-JJ styles
+                                }
+                                // For JJ styles, continue processing
+                            } else if !runner.add_diagnostic(opts, diag) {
                                 return false;
                             }
                         }
+                    } else if let Some(region) = conflict.as_mut() {
+                        region.source.push_str(line);
                     }
                 }
 
-                if !opts.disables.contains(&LongLine) && runner.can_add_issue("information") {
+                if !opts.disables.contains(&LongLine)
+                    && runner.can_add_issue(opts.severity(LongLine))
+                {
                     // Only check if line length is somewhat large to avoid cost on short lines
-                    if trimmed.len() > opts.line_length / 4 {
-                        let visual_width = calculate_width(trimmed);
+                    if trimmed.len() > opts.line_length / opts.tab_width {
+                        let visual_width = calculate_width(trimmed, opts.tab_width);
                         if visual_width > opts.line_length {
-                            let limit = char_col_at_visual_width(trimmed, opts.line_length);
+                            let limit =
+                                char_col_at_visual_width(trimmed, opts.line_length, opts.tab_width);
                             let diag = Diagnostic {
                                 file: filename.to_string(),
                                 lnum,
                                 end_lnum: lnum,
                                 col: limit,
                                 end_col: trimmed.chars().count().saturating_sub(1),
-                                severity: "information".to_string(),
+                                severity: opts.severity(LongLine).to_string(),
                                 source: line.to_string(),
                                 source_lnum: lnum,
                                 code: "long-line".to_string(),
@@ -240,18 +1756,68 @@ pub fn lint_lines<R: BufRead>(
                                     visual_width, opts.line_length
                                 ),
                                 helpers: None,
+                                fixes: None,
+                            };
+                            if !runner.add_diagnostic(opts, diag) {
+                                return false;
+                            }
+                        }
+                    }
+                }
+
+                if first_content_lnum.is_none() {
+                    if trimmed.trim_start_matches([' ', '\t']).is_empty() {
+                        leading_lines.push_str(line);
+                    } else {
+                        first_content_lnum = Some(lnum);
+                        if lnum > 0
+                            && !opts.disables.contains(&LeadingBlank)
+                            && runner.can_add_issue(opts.severity(LeadingBlank))
+                        {
+                            let helper = Helper {
+                                message: "First non-blank line".to_string(),
+                                lnum,
+                                end_lnum: lnum,
+                                col: 0,
+                                end_col: trimmed.chars().count().saturating_sub(1),
+                                source: None,
+                            };
+                            let diag = Diagnostic {
+                                file: filename.to_string(),
+                                lnum: 0,
+                                end_lnum: lnum - 1,
+                                col: 0,
+                                end_col: 0,
+                                severity: opts.severity(LeadingBlank).to_string(),
+                                source: std::mem::take(&mut leading_lines) + line,
+                                source_lnum: 0,
+                                code: "leading-blank".to_string(),
+                                message: format!("Blank lines at the start of the file ({})", lnum),
+                                helpers: Some(vec![helper]),
+                                fixes: Some(vec![Fix {
+                                    lnum: 0,
+                                    end_lnum: lnum,
+                                    replacement: String::new(),
+                                    bom: None,
+                                }]),
                             };
                             if !runner.add_diagnostic(opts, diag) {
                                 return false;
                             }
                         }
+                        leading_lines = String::new();
                     }
                 }
 
-                if !opts.disables.contains(&ConsecutiveBlank) && runner.can_add_issue("information")
+                if !opts.disables.contains(&ConsecutiveBlank)
+                    && runner.can_add_issue(opts.severity(ConsecutiveBlank))
                 {
                     if !trimmed.is_empty() {
-                        if trailing_blank_count > opts.consecutive_blank {
+                        // Runs before the first line with content are reported by leading-blank
+                        let leading_run = first_content_lnum.is_none_or(|first| first == lnum);
+                        if trailing_blank_count > opts.consecutive_blank
+                            && (!leading_run || opts.disables.contains(&LeadingBlank))
+                        {
                             let mut helpers: Vec<Helper> = Vec::new();
                             if !non_blank_lnum.is_negative() {
                                 helpers.push(Helper {
@@ -260,6 +1826,7 @@ pub fn lint_lines<R: BufRead>(
                                     end_lnum: non_blank_lnum as usize,
                                     col: 0,
                                     end_col: non_blank_line.chars().count().saturating_sub(1),
+                                    source: None,
                                 });
                             }
                             helpers.push(Helper {
@@ -268,6 +1835,7 @@ pub fn lint_lines<R: BufRead>(
                                 end_lnum: lnum,
                                 col: 0,
                                 end_col: trimmed.chars().count().saturating_sub(1),
+                                source: None,
                             });
 
                             let diag = Diagnostic {
@@ -276,7 +1844,7 @@ pub fn lint_lines<R: BufRead>(
                                 end_lnum: lnum - 1,
                                 col: 0,
                                 end_col: 0,
-                                severity: "information".to_string(),
+                                severity: opts.severity(ConsecutiveBlank).to_string(),
                                 source: if non_blank_lnum < 0 {
                                     format!("{}{}\n", "\n".repeat(trailing_blank_count), trimmed)
                                 } else {
@@ -294,6 +1862,7 @@ pub fn lint_lines<R: BufRead>(
                                     trailing_blank_count, opts.consecutive_blank
                                 ),
                                 helpers: Some(helpers),
+                                fixes: None,
                             };
                             if !runner.add_diagnostic(opts, diag) {
                                 return false;
@@ -318,14 +1887,187 @@ pub fn lint_lines<R: BufRead>(
         }
     }
 
+    if let Some(mut diag) = eol_unreported {
+        diag.message = format!(
+            "{} more lines with line endings other than {}",
+            eol_unreported_count,
+            expected_eol.unwrap_or(LineEnding::Lf).name()
+        );
+        diag.helpers = first_eol.map(|helper| vec![helper]);
+        runner.add_diagnostic(opts, diag);
+    }
+
+    if let Some(region) = conflict
+        && runner.can_add_issue(opts.severity(ConflictMarker))
+        && !runner.add_diagnostic(opts, region.unterminated(filename, opts, conflict_style))
+    {
+        return false;
+    }
+
+    if let Some(line) = first_excess_line
+        && !opts.disables.contains(&MaxLines)
+        && runner.can_add_issue(opts.severity(MaxLines))
+    {
+        let diag = Diagnostic {
+            file: filename.to_string(),
+            lnum: opts.max_lines,
+            end_lnum: opts.max_lines,
+            col: 0,
+            end_col: line
+                .trim_end_matches(['\r', '\n'])
+                .chars()
+                .count()
+                .saturating_sub(1),
+            severity: opts.severity(MaxLines).to_string(),
+            source: line,
+            source_lnum: opts.max_lines,
+            code: "max-lines".to_string(),
+            message: format!("Too many lines ({}/{})", line_idx, opts.max_lines),
+            helpers: None,
+            fixes: None,
+        };
+        if !runner.add_diagnostic(opts, diag) {
+            return false;
+        }
+    }
+
+    if line_idx > 0
+        && header_found < header_patterns.len()
+        && runner.can_add_issue(opts.severity(Header))
+    {
+        let missing = header_patterns.len() - header_found;
+        let message = if missing == 1 {
+            format!(
+                "Missing header line '{}' in the first {} lines",
+                opts.header[header_found], opts.header_lines
+            )
+        } else {
+            format!(
+                "Missing {} header lines in the first {} lines, starting with '{}'",
+                missing, opts.header_lines, opts.header[header_found]
+            )
+        };
+        // Only a header missing as a whole is inserted, so that no line is duplicated
+        let fixes = match (&opts.header_comment, header_found) {
+            (Some(comment), 0) => {
+                let (next_blank, eol) = header_insert.unwrap_or((true, "\n".to_string()));
+                let year = current_year().to_string();
+                let mut replacement: String = opts
+                    .header
+                    .iter()
+                    .map(|line| format!("{} {}{}", comment, line.replace("{year}", &year), eol))
+                    .collect();
+                if !next_blank {
+                    replacement.push_str(&eol);
+                }
+                Some(vec![Fix {
+                    lnum: header_insert_lnum,
+                    end_lnum: header_insert_lnum,
+                    replacement,
+                    bom: None,
+                }])
+            }
+            _ => None,
+        };
+        let diag = Diagnostic {
+            file: filename.to_string(),
+            lnum: 0,
+            end_lnum: 0,
+            col: 0,
+            end_col: 0,
+            severity: opts.severity(Header).to_string(),
+            source: first_line,
+            source_lnum: 0,
+            code: "header".to_string(),
+            message,
+            helpers: None,
+            fixes,
+        };
+        if !runner.add_diagnostic(opts, diag) {
+            return false;
+        }
+    }
+
+    // Lines indented against the dominant style of the file; ties go to the first indented line
+    // but are only reported, since converting either way could rewrite the wrong lines
+    let tab_count = indented_lines.iter().filter(|(_, tabs)| *tabs).count();
+    let space_count = indented_lines.len() - tab_count;
+    if let Some((_, first_tabs)) = indented_lines.first() {
+        let dominant_tabs = match tab_count.cmp(&space_count) {
+            std::cmp::Ordering::Equal => *first_tabs,
+            ordering => ordering.is_gt(),
+        };
+        let first_dominant = indented_lines
+            .iter()
+            .find(|(_, tabs)| *tabs == dominant_tabs)
+            .zip(indented_sources[dominant_tabs as usize].first())
+            .map(|((lnum, _), line)| Helper {
+                message: format!("First line indented with {}", indent_name(dominant_tabs)),
+                lnum: *lnum,
+                end_lnum: *lnum,
+                col: 0,
+                end_col: line.len() - line.trim_start_matches([' ', '\t']).len() - 1,
+                source: Some(line.clone()),
+            });
+        let minority_lines = indented_lines
+            .iter()
+            .filter(|(_, tabs)| *tabs != dominant_tabs)
+            .zip(&indented_sources[!dominant_tabs as usize]);
+        for ((lnum, _), line) in minority_lines {
+            if !runner.can_add_issue(opts.severity(Indent)) {
+                break;
+            }
+            let diag = Diagnostic {
+                file: filename.to_string(),
+                lnum: *lnum,
+                end_lnum: *lnum,
+                col: 0,
+                end_col: line.len() - line.trim_start_matches([' ', '\t']).len() - 1,
+                severity: opts.severity(Indent).to_string(),
+                source: line.clone(),
+                source_lnum: *lnum,
+                code: "indent".to_string(),
+                message: format!(
+                    "Indentation uses {}, but most indented lines use {} ({}/{})",
+                    indent_name(!dominant_tabs),
+                    indent_name(dominant_tabs),
+                    if dominant_tabs {
+                        tab_count
+                    } else {
+                        space_count
+                    },
+                    indented_lines.len()
+                ),
+                helpers: first_dominant.clone().map(|helper| vec![helper]),
+                fixes: Some(convert_indentation(line, dominant_tabs, opts.indent_width))
+                    .filter(|replacement| tab_count != space_count && replacement != line)
+                    .map(|replacement| {
+                        vec![Fix {
+                            lnum: *lnum,
+                            end_lnum: *lnum + 1,
+                            replacement,
+                            bom: None,
+                        }]
+                    }),
+            };
+            if !runner.add_diagnostic(opts, diag) {
+                return false;
+            }
+        }
+    }
+
     // Post-loop checks (FinalNewline and end-of-file ConsecutiveBlank)
     if let Some((lnum, col, raw_line, has_eol)) = last_line_data {
         let trimmed_last = &raw_line.trim_end_matches(['\r', '\n']);
 
+        // Blank lines at the end are already reported by the stricter final newline policy
+        let final_blank_reported = opts.final_newline == FinalNewlinePolicy::RequireSingle
+            && !opts.disables.contains(&FinalNewline);
         if !opts.disables.contains(&ConsecutiveBlank)
-            && runner.can_add_issue("information")
+            && runner.can_add_issue(opts.severity(ConsecutiveBlank))
             && trimmed_last.is_empty()
             && trailing_blank_count > opts.consecutive_blank
+            && !final_blank_reported
         {
             let helpers = if non_blank_lnum >= 0 {
                 Some(vec![Helper {
@@ -334,6 +2076,7 @@ pub fn lint_lines<R: BufRead>(
                     end_lnum: non_blank_lnum as usize,
                     col: 0,
                     end_col: non_blank_line.chars().count().saturating_sub(1),
+                    source: None,
                 }])
             } else {
                 None
@@ -345,7 +2088,7 @@ pub fn lint_lines<R: BufRead>(
                 end_lnum: lnum,
                 col: 0,
                 end_col: 0,
-                severity: "information".to_string(),
+                severity: opts.severity(ConsecutiveBlank).to_string(),
                 source: if non_blank_lnum < 0 {
                     "\n".repeat(trailing_blank_count)
                 } else {
@@ -358,24 +2101,110 @@ pub fn lint_lines<R: BufRead>(
                     trailing_blank_count, opts.consecutive_blank
                 ),
                 helpers,
+                fixes: None,
             };
             runner.add_diagnostic(opts, diag);
         }
 
-        if !opts.disables.contains(&FinalNewline) && runner.can_add_issue("information") && !has_eol
+        if let Some(blank_lnum) = eof_blank_lnum
+            && opts.whitespace.blank_at_eof
+            && !opts.disables.contains(&BlankAtEof)
+            && runner.can_add_issue(opts.severity(BlankAtEof))
         {
+            let count = lnum - blank_lnum + 1;
             let diag = Diagnostic {
                 file: filename.to_string(),
-                lnum,
+                lnum: blank_lnum,
                 end_lnum: lnum,
+                col: 0,
+                end_col: 0,
+                severity: opts.severity(BlankAtEof).to_string(),
+                source: eof_blank_lines,
+                source_lnum: blank_lnum,
+                code: "blank-at-eof".to_string(),
+                message: if count == 1 {
+                    "Blank line at end of file".to_string()
+                } else {
+                    format!("{} blank lines at end of file", count)
+                },
+                helpers: None,
+                fixes: Some(vec![Fix {
+                    lnum: blank_lnum,
+                    end_lnum: lnum + 1,
+                    replacement: String::new(),
+                    bom: None,
+                }]),
+            };
+            runner.add_diagnostic(opts, diag);
+        }
+
+        // Empty lines after the last line with content, or every line of a blank file
+        let blank_start = final_content_lnum.map_or(0, |content| content + 1);
+        let blank_count = lnum + 1 - blank_start;
+        let (content_line, blank_lines) = match final_content_lnum {
+            Some(_) => final_lines.split_once('\n').unwrap_or((&final_lines, "")),
+            None => ("", final_lines.as_str()),
+        };
+        let problem = match opts.final_newline {
+            FinalNewlinePolicy::RequireSingle | FinalNewlinePolicy::Require if !has_eol => {
+                let eol = expected_eol.unwrap_or(LineEnding::Lf).as_str();
+                Some((
+                    lnum,
+                    col,
+                    lnum,
+                    "Missing final newline".to_string(),
+                    raw_line.clone(),
+                    format!("{}{}", raw_line, eol),
+                ))
+            }
+            FinalNewlinePolicy::RequireSingle if blank_count > 0 => Some((
+                blank_start,
+                0,
+                lnum,
+                match final_content_lnum {
+                    Some(_) => {
+                        format!("File ends with {} newlines instead of one", blank_count + 1)
+                    }
+                    None => "File contains only blank lines".to_string(),
+                },
+                blank_lines.to_string(),
+                String::new(),
+            )),
+            FinalNewlinePolicy::Forbid if has_eol => {
+                let content = content_line.trim_end_matches('\r');
+                Some((
+                    final_content_lnum.unwrap_or(0),
+                    content.chars().count(),
+                    lnum,
+                    "File ends with a newline".to_string(),
+                    final_lines.clone(),
+                    content.to_string(),
+                ))
+            }
+            _ => None,
+        };
+        if let Some((start, col, end_lnum, message, source, replacement)) = problem
+            && !opts.disables.contains(&FinalNewline)
+            && runner.can_add_issue(opts.severity(FinalNewline))
+        {
+            let diag = Diagnostic {
+                file: filename.to_string(),
+                lnum: start,
+                end_lnum,
                 col,
-                end_col: col,
-                severity: "information".to_string(),
-                source: raw_line,
-                source_lnum: lnum,
+                end_col: if end_lnum == start { col } else { 0 },
+                severity: opts.severity(FinalNewline).to_string(),
+                source,
+                source_lnum: start,
                 code: "final-newline".to_string(),
-                message: "Missing final newline".to_string(),
+                message,
                 helpers: None,
+                fixes: Some(vec![Fix {
+                    lnum: start,
+                    end_lnum: end_lnum + 1,
+                    replacement,
+                    bom: None,
+                }]),
             };
             runner.add_diagnostic(opts, diag);
         }
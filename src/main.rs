@@ -1,44 +1,66 @@
 mod args;
+mod config;
 mod enums;
+mod fix;
+mod init;
 mod lint;
 mod output;
+mod rules;
+mod secret;
 mod types;
 mod util;
+mod vcs;
 
 use clap_complete::{Shell, generate};
 use glob::glob;
 use std::fs::File;
 use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
 
 use crate::types::DiagnosticType;
 
 use crate::args::build_cli;
+use crate::config::{Config, DEFAULT_CONFIG_FILE, DEFAULT_HEADER_LINES};
 use crate::enums::{
-    ConflictMarkerStyle,
-    DisableCheck::{self, ConsecutiveBlank, LongLine},
-    Format,
+    BomPolicy, ConflictMarkerStyle,
+    DisableCheck::{self, ConsecutiveBlank, LongLine, MaxFileSize, MergeArtifact},
+    EncodingPolicy, EolStyle, FinalNewlinePolicy, Format, IndentStyle, NormalizationForm, Severity,
 };
-use crate::lint::lint_lines;
-use crate::output::{print_diagnostics_json, print_diagnostics_jsonl, print_diagnostics_plain};
-use crate::types::{LintOptions, LintRunner};
+use crate::fix::fix_file;
+use crate::init::init_config;
+use crate::lint::{lint_file_size, lint_lines, lint_merge_artifact, lint_paths};
+use crate::output::{
+    print_diagnostics_json, print_diagnostics_jsonl, print_diagnostics_plain,
+    print_rule_explanation, print_rules,
+};
+use crate::rules::rule_info;
+use crate::types::{LintOptions, LintRunner, WhitespaceClasses};
+use crate::vcs::{detect_conflict_marker_style, git_whitespace};
 
 const SMALL_FILE_THRESHOLD: u64 = 1024 * 1024;
 const SMALL_BUFFER_SIZE: usize = 64 * 1024;
 const LARGE_BUFFER_SIZE: usize = 256 * 1024;
 
-fn buffer_size_for_file(path: &std::path::Path) -> usize {
-    match path.metadata() {
-        Ok(metadata) => {
-            if metadata.len() < SMALL_FILE_THRESHOLD {
-                SMALL_BUFFER_SIZE
-            } else {
-                LARGE_BUFFER_SIZE
-            }
-        }
-        Err(_) => SMALL_BUFFER_SIZE,
+fn buffer_size_for_file(file_size: u64) -> usize {
+    if file_size < SMALL_FILE_THRESHOLD {
+        SMALL_BUFFER_SIZE
+    } else {
+        LARGE_BUFFER_SIZE
     }
 }
 
+#[cfg(unix)]
+fn is_executable(metadata: &std::fs::Metadata) -> Option<bool> {
+    use std::os::unix::fs::PermissionsExt;
+    Some(metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+// Other platforms have no executable bit to compare shebangs with
+#[cfg(not(unix))]
+fn is_executable(_metadata: &std::fs::Metadata) -> Option<bool> {
+    None
+}
+
 fn print_diagnostics<W: Write>(
     matches: &clap::ArgMatches,
     runner: &LintRunner,
@@ -52,20 +74,130 @@ fn print_diagnostics<W: Write>(
     writer.flush().unwrap();
 }
 
+// Prefer an explicitly given command-line value, then the configuration file, then the default
+fn option_value<T: Clone + Send + Sync + 'static>(
+    matches: &clap::ArgMatches,
+    id: &str,
+    config_value: Option<T>,
+) -> T {
+    match (matches.value_source(id), config_value) {
+        (Some(clap::parser::ValueSource::DefaultValue), Some(value)) => value,
+        _ => matches.get_one::<T>(id).cloned().unwrap(),
+    }
+}
+
+fn from_command_line(matches: &clap::ArgMatches, id: &str) -> bool {
+    matches.value_source(id) == Some(clap::parser::ValueSource::CommandLine)
+}
+
+// Apply the overrides of the configuration file matching a path, unless given on the command line
+fn file_options(
+    matches: &clap::ArgMatches,
+    config: &Config,
+    path: &Path,
+    opts: &LintOptions,
+) -> LintOptions {
+    let mut opts = opts.clone();
+    for ov in config.overrides.iter().filter(|ov| ov.matches(path)) {
+        // Rules are disabled in addition to the ones given on the command line or in the file
+        if let Some(disable) = &ov.disable {
+            opts.disables.extend(disable);
+        }
+        if let Some(tab_width) = ov.tab_width
+            && !from_command_line(matches, "tab-width")
+        {
+            opts.tab_width = tab_width;
+        }
+        if let Some(indent_style) = ov.indent_style
+            && !from_command_line(matches, "indent-style")
+        {
+            opts.indent_style = indent_style;
+        }
+        if let Some(indent_width) = ov.indent_width
+            && !from_command_line(matches, "indent-width")
+        {
+            opts.indent_width = indent_width;
+        }
+        if let Some(header) = &ov.header {
+            opts.header = header.clone();
+        }
+        if let Some(header_comment) = &ov.header_comment {
+            opts.header_comment = Some(header_comment.clone());
+        }
+        if let Some(header_lines) = ov.header_lines {
+            opts.header_lines = header_lines;
+        }
+        if let Some(allowed_control_chars) = &ov.allowed_control_chars {
+            opts.allowed_control_chars = allowed_control_chars.clone();
+        }
+    }
+    opts
+}
+
 fn main() {
     let cmd = build_cli();
     let matches = cmd.get_matches();
 
-    if let Some(("generate-completion", sub_m)) = matches.subcommand() {
-        let shell = *sub_m.get_one::<Shell>("shell").unwrap();
-        let mut cmd = build_cli();
-        generate(shell, &mut cmd, "genlint", &mut std::io::stdout());
-        return;
+    match matches.subcommand() {
+        Some(("generate-completion", sub_m)) => {
+            let shell = *sub_m.get_one::<Shell>("shell").unwrap();
+            let mut cmd = build_cli();
+            generate(shell, &mut cmd, "genlint", &mut std::io::stdout());
+            return;
+        }
+        Some(("init", sub_m)) => {
+            let patterns: Vec<String> = sub_m
+                .get_many::<String>("patterns")
+                .unwrap()
+                .cloned()
+                .collect();
+            match init_config(
+                Path::new(DEFAULT_CONFIG_FILE),
+                &patterns,
+                sub_m.get_flag("force"),
+            ) {
+                Ok(files) => {
+                    eprintln!("Wrote {} from {} scanned files", DEFAULT_CONFIG_FILE, files)
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+        Some(("rules", _)) => {
+            let mut writer = BufWriter::new(std::io::stdout());
+            print_rules(&mut writer);
+            writer.flush().unwrap();
+            return;
+        }
+        Some(("explain", sub_m)) => {
+            let check = *sub_m.get_one::<DisableCheck>("rule").unwrap();
+            let mut writer = BufWriter::new(std::io::stdout());
+            print_rule_explanation(&mut writer, rule_info(check));
+            writer.flush().unwrap();
+            return;
+        }
+        _ => {}
     }
 
+    let config = match matches.get_one::<PathBuf>("config") {
+        Some(path) => Config::load(path),
+        None if Path::new(DEFAULT_CONFIG_FILE).is_file() => {
+            Config::load(Path::new(DEFAULT_CONFIG_FILE))
+        }
+        None => Ok(Config::default()),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+
     let disables: Vec<_> = matches
         .get_many::<DisableCheck>("disable")
         .map(|vals| vals.cloned().collect())
+        .or(config.disable.clone())
         .unwrap_or_default();
     if disables.contains(&LongLine)
         && matches
@@ -86,26 +218,129 @@ fn main() {
         std::process::exit(1);
     }
 
-    let max_line_length = *matches.get_one::<usize>("max-line-length").unwrap();
-    let max_consecutive_blank = *matches.get_one::<usize>("max-consecutive-blank").unwrap();
-    let max_errors = *matches.get_one::<usize>("max-errors").unwrap();
-    let max_warnings = *matches.get_one::<usize>("max-warnings").unwrap();
-    let max_info = *matches.get_one::<usize>("max-info").unwrap();
-    let text_mode = matches.get_flag("text");
-    let conflict_marker_style = *matches
-        .get_one::<ConflictMarkerStyle>("conflict-marker-style")
-        .unwrap();
+    let max_line_length = option_value(&matches, "max-line-length", config.max_line_length);
+    let tab_width = option_value(&matches, "tab-width", config.tab_width);
+    let max_consecutive_blank = option_value(
+        &matches,
+        "max-consecutive-blank",
+        config.max_consecutive_blank,
+    );
+    let max_lines = option_value(&matches, "max-lines", config.max_lines);
+    let max_file_size = option_value(&matches, "max-file-size", config.max_file_size);
+    let max_path_length = option_value(&matches, "max-path-length", config.max_path_length);
+    let max_errors = option_value(&matches, "max-errors", config.max_errors);
+    let max_warnings = option_value(&matches, "max-warnings", config.max_warnings);
+    let max_info = option_value(&matches, "max-info", config.max_info);
+    let text_mode = matches.get_flag("text") || config.text.unwrap_or(false);
+    let mut conflict_marker_style: ConflictMarkerStyle = option_value(
+        &matches,
+        "conflict-marker-style",
+        config.conflict_marker_style,
+    );
+    if conflict_marker_style == ConflictMarkerStyle::Detect {
+        conflict_marker_style = std::env::current_dir().map_or(ConflictMarkerStyle::Git, |dir| {
+            detect_conflict_marker_style(&dir)
+        });
+    }
+    let strict_conflict_markers = matches.get_flag("strict-conflict-markers")
+        || config.strict_conflict_markers.unwrap_or(false);
+    let eol_style: EolStyle = option_value(&matches, "eol-style", config.eol_style);
+    let final_newline: FinalNewlinePolicy =
+        option_value(&matches, "final-newline", config.final_newline);
+    let encoding: EncodingPolicy = option_value(&matches, "encoding", config.encoding);
+    let bom: BomPolicy = option_value(&matches, "bom", config.bom);
+    let normalization_form: NormalizationForm =
+        option_value(&matches, "normalization-form", config.normalization_form);
+    let indent_style: IndentStyle = option_value(&matches, "indent-style", config.indent_style);
+    let indent_width = option_value(&matches, "indent-width", config.indent_width);
+    let todo_keywords: Vec<String> =
+        match (matches.value_source("todo-keywords"), &config.todo_keywords) {
+            (Some(clap::parser::ValueSource::DefaultValue), Some(keywords)) => keywords.clone(),
+            _ => matches
+                .get_many::<String>("todo-keywords")
+                .unwrap()
+                .cloned()
+                .collect(),
+        };
+    // Classes given explicitly start from genlint's defaults, while core.whitespace starts from
+    // Git's defaults, so only a leading `-` turns a class off
+    let whitespace = match matches
+        .get_one::<String>("whitespace")
+        .or(config.whitespace.as_ref())
+        .map(String::as_str)
+    {
+        Some("git") => {
+            let value = std::env::current_dir()
+                .ok()
+                .and_then(|dir| git_whitespace(&dir))
+                .unwrap_or_default();
+            WhitespaceClasses::parse(&value, WhitespaceClasses::GIT_DEFAULT)
+                .map_err(|e| format!("core.whitespace: {}", e))
+        }
+        Some(value) => WhitespaceClasses::parse(value, WhitespaceClasses::DEFAULT),
+        None => Ok(WhitespaceClasses::DEFAULT),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+    let todo_issue_pattern = match matches.get_one::<String>("todo-issue-pattern") {
+        Some(pattern) => Some(glob::Pattern::new(pattern).unwrap_or_else(|e| {
+            eprintln!(
+                "Error: invalid pattern '{}' for '--todo-issue-pattern': {}",
+                pattern, e
+            );
+            std::process::exit(1);
+        })),
+        None => config.todo_issue_pattern.clone(),
+    };
+    // Command-line overrides come last so that they win over the configuration file
+    let severities: Vec<(DisableCheck, Severity)> = config
+        .severities
+        .iter()
+        .cloned()
+        .chain(
+            matches
+                .get_many::<(DisableCheck, Severity)>("severity")
+                .into_iter()
+                .flatten()
+                .cloned(),
+        )
+        .collect();
     let lint_opts = LintOptions {
         disables,
         line_length: max_line_length,
+        tab_width,
         consecutive_blank: max_consecutive_blank,
+        max_lines,
+        max_file_size,
+        max_path_length,
         max_errors,
         max_warnings,
         max_info,
         text_mode,
         conflict_marker_style,
+        strict_conflict_markers,
+        eol_style,
+        final_newline,
+        encoding,
+        bom,
+        normalization_form,
+        indent_style,
+        indent_width,
+        whitespace,
+        secret_allowlist: config.secret_allowlist.clone(),
+        todo_keywords,
+        todo_issue_pattern,
+        header: config.header.clone().unwrap_or_default(),
+        header_comment: config.header_comment.clone(),
+        header_lines: config.header_lines.unwrap_or(DEFAULT_HEADER_LINES),
+        allowed_control_chars: config.allowed_control_chars.clone(),
+        executable: None,
+        severities,
     };
 
+    let fix_mode = matches.get_flag("fix");
     let mut runner = LintRunner::new();
     let mut writer = BufWriter::new(std::io::stdout());
 
@@ -113,36 +348,77 @@ fn main() {
         let stdin = io::stdin().lock();
         let reader = BufReader::new(stdin);
         if !lint_lines("<stdin>", reader, &mut runner, &lint_opts) {
-            print_diagnostics(&matches, &runner, &mut writer);
-            print_summary(&runner);
-            return;
+            finish(&matches, &runner, &mut writer);
         }
     }
 
+    let mut paths = Vec::new();
     if let Some(inputs) = matches.get_many::<String>("input") {
         for pattern in inputs {
             for entry in glob(pattern).expect("Failed to read glob pattern") {
-                let path = entry.unwrap();
-                if let Ok(file) = File::open(&path) {
-                    let buffer_size = buffer_size_for_file(&path);
-                    let reader = BufReader::with_capacity(buffer_size, file);
-                    if !lint_lines(
-                        path.to_string_lossy().as_ref(),
-                        reader,
-                        &mut runner,
-                        &lint_opts,
-                    ) {
-                        print_diagnostics(&matches, &runner, &mut writer);
-                        print_summary(&runner);
-                        return;
-                    }
-                }
+                paths.push(entry.unwrap());
             }
         }
     }
 
-    print_diagnostics(&matches, &runner, &mut writer);
-    print_summary(&runner);
+    // Path rules compare the input paths with each other, so they run before any file is read
+    let filenames: Vec<String> = paths
+        .iter()
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect();
+    if !lint_paths(&filenames, &mut runner, &lint_opts) {
+        finish(&matches, &runner, &mut writer);
+    }
+
+    for (path, filename) in paths.iter().zip(&filenames) {
+        let mut file_opts = file_options(&matches, &config, path, &lint_opts);
+        if !file_opts.disables.contains(&MergeArtifact)
+            && !lint_merge_artifact(filename, &mut runner, &file_opts)
+        {
+            finish(&matches, &runner, &mut writer);
+        }
+        let metadata = path.metadata().ok();
+        let file_size = metadata.as_ref().map_or(0, |metadata| metadata.len());
+        file_opts.executable = metadata.as_ref().and_then(is_executable);
+        // Oversized files are reported without being read
+        if !file_opts.disables.contains(&MaxFileSize)
+            && file_opts.max_file_size > 0
+            && file_size > file_opts.max_file_size
+        {
+            if !lint_file_size(filename, file_size, &mut runner, &file_opts) {
+                finish(&matches, &runner, &mut writer);
+            }
+            continue;
+        }
+        if fix_mode {
+            match fix_file(path, &file_opts) {
+                Ok(0) => {}
+                Ok(fixed) => eprintln!("Fixed {} issues in '{}'", fixed, path.display()),
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        if let Ok(file) = File::open(path) {
+            let buffer_size = buffer_size_for_file(file_size);
+            let reader = BufReader::with_capacity(buffer_size, file);
+            if !lint_lines(filename, reader, &mut runner, &file_opts) {
+                finish(&matches, &runner, &mut writer);
+            }
+        }
+    }
+
+    finish(&matches, &runner, &mut writer);
+}
+
+// Print the results and exit with a failure status when any error was reported
+fn finish<W: Write>(
+    matches: &clap::ArgMatches,
+    runner: &LintRunner,
+    writer: &mut BufWriter<W>,
+) -> ! {
+    print_diagnostics(matches, runner, writer);
+    print_summary(runner);
+    let (error_count, _, _) = runner.diagnostic_counts();
+    std::process::exit(if error_count > 0 { 1 } else { 0 });
 }
 
 fn print_summary(runner: &LintRunner) {
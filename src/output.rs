@@ -1,7 +1,8 @@
+use crate::rules::{RULES, RuleInfo};
 use crate::types::Diagnostic;
 use crate::util::{char_index_to_byte_range, coord_to_pos, pos_to_annotation};
 use annotate_snippets::renderer::DecorStyle;
-use annotate_snippets::{AnnotationKind, Group, Renderer, Snippet};
+use annotate_snippets::{AnnotationKind, Group, Level, Origin, Renderer, Snippet};
 use genlint::util::severity_to_level;
 use serde_partial::SerializePartial;
 use std::io::{BufWriter, Write};
@@ -9,6 +10,25 @@ use std::io::{BufWriter, Write};
 pub fn print_diagnostics_plain<W: Write>(writer: &mut BufWriter<W>, diagnostics: &[Diagnostic]) {
     let mut report = Vec::new();
     for diag in diagnostics {
+        // Diagnostics about a whole file have no source to annotate
+        if diag.source.is_empty() {
+            report.push(
+                Group::with_title(
+                    severity_to_level(diag.severity.as_str())
+                        .primary_title(&diag.message)
+                        .id(diag.code.as_str()),
+                )
+                .element(Origin::path(diag.file.as_str()))
+                // Helpers of a whole file, like the paths of a collision, become notes
+                .elements(
+                    diag.helpers
+                        .iter()
+                        .flatten()
+                        .map(|helper| Level::NOTE.message(helper.message.as_str())),
+                ),
+            );
+            continue;
+        }
         let char_pos = coord_to_pos(&diag.source, diag.source_lnum, diag.lnum, diag.col);
         let char_end_pos =
             coord_to_pos(&diag.source, diag.source_lnum, diag.end_lnum, diag.end_col);
@@ -20,8 +40,13 @@ pub fn print_diagnostics_plain<W: Write>(writer: &mut BufWriter<W>, diagnostics:
             None,
             AnnotationKind::Primary,
         )];
+        let mut detached = Vec::new();
         if let Some(helpers) = &diag.helpers {
             for helper in helpers.iter() {
+                if let Some(source) = &helper.source {
+                    detached.push((helper, source));
+                    continue;
+                }
                 let char_pos =
                     coord_to_pos(&diag.source, diag.source_lnum, helper.lnum, helper.col);
                 let char_end_pos = coord_to_pos(
@@ -41,7 +66,7 @@ pub fn print_diagnostics_plain<W: Write>(writer: &mut BufWriter<W>, diagnostics:
             }
         };
 
-        let message = Group::with_title(
+        let mut message = Group::with_title(
             severity_to_level(diag.severity.as_str())
                 .primary_title(&diag.message)
                 .id(diag.code.as_str()),
@@ -53,6 +78,24 @@ pub fn print_diagnostics_plain<W: Write>(writer: &mut BufWriter<W>, diagnostics:
                 .fold(true)
                 .annotations(annotations),
         );
+        for (helper, source) in detached {
+            let char_pos = coord_to_pos(source, helper.lnum, helper.lnum, helper.col);
+            let char_end_pos = coord_to_pos(source, helper.lnum, helper.end_lnum, helper.end_col);
+            let (pos, _) = char_index_to_byte_range(source, char_pos);
+            let (_, end_pos_exclusive) = char_index_to_byte_range(source, char_end_pos);
+            message = message.element(
+                Snippet::source(source.as_str())
+                    .line_start(helper.lnum + 1)
+                    .path(diag.file.as_str())
+                    .fold(true)
+                    .annotation(pos_to_annotation(
+                        pos,
+                        end_pos_exclusive.saturating_sub(1),
+                        Some(helper.message.as_str()),
+                        AnnotationKind::Context,
+                    )),
+            );
+        }
 
         report.push(message)
     }
@@ -65,7 +108,7 @@ pub fn print_diagnostics_json<W: Write>(writer: &mut BufWriter<W>, diagnostics:
         writer,
         &diagnostics
             .iter()
-            .map(|d| d.without_fields(|d| [d.source, d.source_lnum, d.helpers]))
+            .map(|d| d.without_fields(|d| [d.source, d.source_lnum, d.helpers, d.fixes]))
             .collect::<Vec<_>>(),
     );
 }
@@ -74,8 +117,69 @@ pub fn print_diagnostics_jsonl<W: Write>(writer: &mut BufWriter<W>, diagnostics:
     for diag in diagnostics {
         let _ = serde_json::to_writer(
             &mut *writer,
-            &diag.without_fields(|d| [d.source, d.source_lnum, d.helpers]),
+            &diag.without_fields(|d| [d.source, d.source_lnum, d.helpers, d.fixes]),
         );
         let _ = writeln!(writer);
     }
 }
+
+pub fn print_rules<W: Write>(writer: &mut BufWriter<W>) {
+    let code_width = RULES.iter().map(|r| r.code.len()).max().unwrap_or(0);
+    let _ = writeln!(
+        writer,
+        "{:<code_width$}  {:<11}  {:<7}  PARAMETERS",
+        "CODE", "SEVERITY", "FIXABLE"
+    );
+    for rule in RULES {
+        let parameters = rule
+            .parameters
+            .iter()
+            .map(|p| format!("{} (default: {})", p.flag, p.default))
+            .collect::<Vec<_>>();
+        let _ = writeln!(
+            writer,
+            "{:<code_width$}  {:<11}  {:<7}  {}",
+            rule.code,
+            rule.check.default_severity().as_str(),
+            if rule.fixable { "yes" } else { "no" },
+            if parameters.is_empty() {
+                "-".to_string()
+            } else {
+                parameters.join(", ")
+            }
+        );
+    }
+}
+
+pub fn print_rule_explanation<W: Write>(writer: &mut BufWriter<W>, rule: &RuleInfo) {
+    let _ = writeln!(writer, "{}: {}", rule.code, rule.summary);
+    let _ = writeln!(
+        writer,
+        "Default severity: {}",
+        rule.check.default_severity().as_str()
+    );
+    let _ = writeln!(
+        writer,
+        "Fixable: {}",
+        if rule.fixable { "yes" } else { "no" }
+    );
+    let _ = writeln!(writer, "\n{}", rule.description);
+    if !rule.parameters.is_empty() {
+        let _ = writeln!(writer, "\nParameters:");
+        for param in rule.parameters {
+            let _ = writeln!(
+                writer,
+                "  {}  {} (default: {})",
+                param.flag, param.help, param.default
+            );
+        }
+    }
+    let _ = writeln!(writer, "\nBad:");
+    for line in rule.bad_example.lines() {
+        let _ = writeln!(writer, "  {}", line);
+    }
+    let _ = writeln!(writer, "\nGood:");
+    for line in rule.good_example.lines() {
+        let _ = writeln!(writer, "  {}", line);
+    }
+}
@@ -0,0 +1,555 @@
+use crate::enums::DisableCheck;
+
+pub struct RuleParameter {
+    pub flag: &'static str,
+    pub help: &'static str,
+    pub default: &'static str,
+}
+
+pub struct RuleInfo {
+    pub check: DisableCheck,
+    pub code: &'static str,
+    pub summary: &'static str,
+    pub description: &'static str,
+    pub fixable: bool,
+    pub parameters: &'static [RuleParameter],
+    pub bad_example: &'static str,
+    pub good_example: &'static str,
+}
+
+// Examples mark a space as `·` and a tab as `→` so that whitespace rules stay readable, and spell
+// out invisible characters as escapes so that they cannot reorder or hide the example itself.
+pub const RULES: &[RuleInfo] = &[
+    RuleInfo {
+        check: DisableCheck::MixIndent,
+        code: "mix-indent",
+        summary: "Mixed tabs and whitespaces in indentation",
+        description: "Reports lines whose indentation contains both tabs and spaces before the \
+            first non-whitespace character. Mixed indentation renders differently depending on \
+            the tab width of the viewer.",
+        fixable: false,
+        parameters: &[],
+        bad_example: "→··let x = 5;",
+        good_example: "→let x = 5;",
+    },
+    RuleInfo {
+        check: DisableCheck::TrailingSpace,
+        code: "trailing-space",
+        summary: "Trailing whitespaces or tabs",
+        description: "Reports spaces and tabs between the last visible character of a line and \
+            the line ending. Trailing whitespace is invisible in most editors and produces noisy \
+            diffs. Lines with only whitespace are reported by `whitespace-only-line` unless it is \
+            disabled. This is the `blank-at-eol` class of Git's `core.whitespace`; unless the \
+            `cr-at-eol` class is set, the carriage return of a CRLF line ending is trailing \
+            whitespace too.",
+        fixable: false,
+        parameters: &[RuleParameter {
+            flag: "--whitespace <CLASSES>",
+            help: "Git whitespace classes, or 'git' to read core.whitespace",
+            default: "blank-at-eol,cr-at-eol",
+        }],
+        bad_example: "let x = 5;··→",
+        good_example: "let x = 5;",
+    },
+    RuleInfo {
+        check: DisableCheck::ConflictMarker,
+        code: "conflict-marker",
+        summary: "Unresolved conflict markers",
+        description: "Reports conflicts left behind by an unfinished merge as a single \
+            diagnostic spanning the conflict, with its sides (ours, base, theirs) labelled. \
+            Conflicts missing a required marker and start or end markers without their \
+            counterpart are reported as malformed. Separator markers such as ======= outside a \
+            conflict are usually heading underlines and are only reported with \
+            `--strict-conflict-markers`, which still ignores them in Markdown and \
+            reStructuredText files. The markers depend on the conflict marker style: `git` uses <<<<<<<, ======= and \
+            >>>>>>>; `git-diff3` additionally uses ||||||| for the common base; `jj` uses \
+            <<<<<<<, %%%%%%%, \\\\\\\\\\\\\\, +++++++ and >>>>>>>; `jj-diff3` uses the \
+            git-diff3 markers; `jj-snapshot` uses <<<<<<<, +++++++, ------- and >>>>>>>. \
+            Jujutsu styles also accept longer markers (11, 15, ... characters) when the \
+            conflicted content itself contains marker-like lines. `auto` recognizes the markers \
+            of every style at once, and `detect` chooses the style of the current repository: \
+            a `.jj` directory selects Jujutsu (using `ui.conflict-marker-style` of the \
+            repository), otherwise `merge.conflictStyle` of the local Git configuration selects \
+            git or git-diff3.",
+        fixable: false,
+        parameters: &[
+            RuleParameter {
+                flag: "--conflict-marker-style <STYLE>",
+                help: "Conflict marker style (git, git-diff3, jj, jj-diff3, jj-snapshot, auto or \
+                    detect)",
+                default: "git",
+            },
+            RuleParameter {
+                flag: "--strict-conflict-markers",
+                help: "Report separator markers outside a conflict",
+                default: "false",
+            },
+        ],
+        bad_example: "<<<<<<< HEAD\nlet x = 1;\n=======\nlet x = 2;\n>>>>>>> feature",
+        good_example: "let x = 2;",
+    },
+    RuleInfo {
+        check: DisableCheck::LongLine,
+        code: "long-line",
+        summary: "Line exceeds the maximum length",
+        description: "Reports lines whose visual width exceeds the maximum line length. Wide \
+            characters such as CJK count as two columns and a tab advances to the next tab \
+            stop.",
+        fixable: false,
+        parameters: &[
+            RuleParameter {
+                flag: "--max-line-length <NUM>",
+                help: "Maximum allowed line length",
+                default: "120",
+            },
+            RuleParameter {
+                flag: "--tab-width <NUM>",
+                help: "Width of a tab stop",
+                default: "4",
+            },
+        ],
+        bad_example: "let message = \"a line that keeps going well past the configured limit\";",
+        good_example: "let message =\n····\"a line that was wrapped before the configured limit\";",
+    },
+    RuleInfo {
+        check: DisableCheck::ConsecutiveBlank,
+        code: "consecutive-blank",
+        summary: "Too many consecutive blank lines",
+        description: "Reports runs of blank lines longer than the maximum, including runs at the \
+            end of a file. Runs at the start of a file are reported by `leading-blank` unless it \
+            is disabled.",
+        fixable: false,
+        parameters: &[RuleParameter {
+            flag: "--max-consecutive-blank <NUM>",
+            help: "Maximum allowed consecutive blank lines",
+            default: "1",
+        }],
+        bad_example: "let x = 5;\n\n\n\nlet y = 10;",
+        good_example: "let x = 5;\n\nlet y = 10;",
+    },
+    RuleInfo {
+        check: DisableCheck::FinalNewline,
+        code: "final-newline",
+        summary: "Final newline policy",
+        description: "Reports files whose last line is not terminated by a line ending. Many \
+            tools treat an unterminated last line as incomplete. With `require-single`, empty \
+            lines after the last line with content are reported too, independently of the \
+            `consecutive-blank` limit, like formatters that strip them. With `forbid`, a file \
+            must not end with a line ending. The fix adds or removes the final line endings.",
+        fixable: true,
+        parameters: &[RuleParameter {
+            flag: "--final-newline <POLICY>",
+            help: "Final newline policy (require-single, require or forbid)",
+            default: "require",
+        }],
+        bad_example: "let x = 5;<EOF>",
+        good_example: "let x = 5;\n<EOF>",
+    },
+    RuleInfo {
+        check: DisableCheck::Eol,
+        code: "eol",
+        summary: "Disallowed or inconsistent line endings",
+        description: "Reports line endings (LF, CRLF or a lone CR) that do not match the \
+            expected style. With `consistent`, the first line ending of a file sets the style \
+            for the rest of it. The first few offending lines are reported one by one and the \
+            rest are aggregated into a single diagnostic.",
+        fixable: true,
+        parameters: &[RuleParameter {
+            flag: "--eol-style <STYLE>",
+            help: "Expected line ending style (lf, crlf or consistent)",
+            default: "consistent",
+        }],
+        bad_example: "let x = 5;\\n\nlet y = 10;\\r\\n",
+        good_example: "let x = 5;\\n\nlet y = 10;\\n",
+    },
+    RuleInfo {
+        check: DisableCheck::BidiChar,
+        code: "bidi-char",
+        summary: "Bidirectional control characters",
+        description: "Reports Unicode bidirectional control characters (U+202A to U+202E and \
+            U+2066 to U+2069). They reorder how text is displayed, so code can look different \
+            from what a compiler reads (CVE-2021-42574, \"Trojan Source\").",
+        fixable: false,
+        parameters: &[],
+        bad_example: "let access = \"user\\u{202E} \\u{2066}// admin only\\u{2069} \\u{2066}\";",
+        good_example: "let access = \"user\"; // admin only",
+    },
+    RuleInfo {
+        check: DisableCheck::InvisibleChar,
+        code: "invisible-char",
+        summary: "Invisible characters",
+        description: "Reports zero-width characters, non-breaking spaces, soft hyphens, byte \
+            order marks after the start of a file and other invisible format characters, which \
+            are often pasted from documents by accident. Zero-width joiners are allowed between \
+            non-ASCII characters, such as in emoji sequences.",
+        fixable: false,
+        parameters: &[],
+        bad_example: "let\\u{00A0}x = 5;\\u{200B}",
+        good_example: "let x = 5;",
+    },
+    RuleInfo {
+        check: DisableCheck::ControlChar,
+        code: "control-char",
+        summary: "Control characters",
+        description: "Reports C0 and C1 control characters other than tab and line endings, such \
+            as terminal escape sequences, form feeds, vertical tabs, NULs and DEL, with their code \
+            point in the message. Characters listed in `allowed-control-chars` in the \
+            configuration file, either as themselves or as code points like \"U+000C\", are \
+            ignored; set it in an `[[overrides]]` table to allow form feeds in Emacs Lisp or C \
+            files only.",
+        fixable: false,
+        parameters: &[],
+        bad_example: "echo \"\\u{1B}[31mred\\u{1B}[0m\"",
+        good_example: "echo \"\\e[31mred\\e[0m\"",
+    },
+    RuleInfo {
+        check: DisableCheck::Confusable,
+        code: "confusable",
+        summary: "Characters confusable with ASCII",
+        description: "Reports characters that look like ASCII but are not, such as smart quotes, \
+            en and em dashes, the non-breaking hyphen, the minus sign and fullwidth forms, which \
+            are often pasted from documents and change the meaning of code. Cyrillic and Greek \
+            letters that look like Latin ones are only reported in words that also contain Latin \
+            letters. Prose such as translated documentation can opt out with \
+            `disable = [\"confusable\"]` in an `[[overrides]]` table of the configuration file.",
+        fixable: false,
+        parameters: &[],
+        bad_example: "if p\\u{0430}ssword \\u{2013}eq \\u{201C}\\u{201D}",
+        good_example: "if password -eq \"\"",
+    },
+    RuleInfo {
+        check: DisableCheck::Normalization,
+        code: "normalization",
+        summary: "Unicode normalization form",
+        description: "Reports lines that are not in the expected Unicode normalization form, such \
+            as decomposed (NFD) text written by macOS tools in a project that uses composed (NFC) \
+            text. Such lines look identical but compare unequal. The diagnostic spans the \
+            characters that change, and the fix normalizes the line.",
+        fixable: true,
+        parameters: &[RuleParameter {
+            flag: "--normalization-form <FORM>",
+            help: "Expected normalization form (nfc, nfd, nfkc or nfkd)",
+            default: "nfc",
+        }],
+        bad_example: "name = \"Jose\\u{0301}\"",
+        good_example: "name = \"Jos\\u{00E9}\"",
+    },
+    RuleInfo {
+        check: DisableCheck::Encoding,
+        code: "encoding",
+        summary: "Invalid or unexpected text encoding",
+        description: "Reports byte sequences that are not valid UTF-8, with their byte offset. \
+            Invalid sequences are replaced so that the rest of the file is still linted. Files \
+            starting with a UTF-16 or UTF-32 byte order mark are skipped like binary files by \
+            default, reported as a whole with `--encoding utf8`, or decoded and linted with \
+            `--encoding detect`.",
+        fixable: false,
+        parameters: &[RuleParameter {
+            flag: "--encoding <POLICY>",
+            help: "Skip (skip), report (utf8) or decode (detect) UTF-16 and UTF-32 files",
+            default: "skip",
+        }],
+        bad_example: "caf\\xE9 (Latin-1 encoded)",
+        good_example: "caf\\xC3\\xA9 (UTF-8 encoded)",
+    },
+    RuleInfo {
+        check: DisableCheck::Bom,
+        code: "bom",
+        summary: "UTF-8 byte order mark policy",
+        description: "Reports a UTF-8 byte order mark at the start of a file when it is \
+            forbidden, or its absence when it is required. The byte order mark never counts \
+            toward the columns or the width of the first line.",
+        fixable: true,
+        parameters: &[RuleParameter {
+            flag: "--bom <POLICY>",
+            help: "Byte order mark policy (forbid, require or ignore)",
+            default: "forbid",
+        }],
+        bad_example: "\\xEF\\xBB\\xBFlet x = 5; (with --bom forbid)",
+        good_example: "let x = 5;",
+    },
+    RuleInfo {
+        check: DisableCheck::Indent,
+        code: "indent",
+        summary: "Indentation style and width",
+        description: "Reports lines indented with tabs when spaces are expected or vice versa. \
+            With `spaces`, the number of indentation spaces must also be a multiple of the \
+            indentation width; with `tabs`, spaces after the leading tabs are allowed for \
+            alignment. With `consistent`, the style used by most indented lines of a file is \
+            expected on the others. With `tabs` and `consistent`, space indents narrower than the \
+            indentation width, such as block comment continuations, are alignment. Styles can be set per glob pattern with \
+            `[[overrides]]` in the configuration file. The fix converts the indentation using the \
+            indentation width, unless the styles of a file are tied.",
+        fixable: true,
+        parameters: &[
+            RuleParameter {
+                flag: "--indent-style <STYLE>",
+                help: "Expected indentation style (tabs, spaces or consistent)",
+                default: "consistent",
+            },
+            RuleParameter {
+                flag: "--indent-width <NUM>",
+                help: "Indentation width, used for space indentation and tab conversion",
+                default: "4",
+            },
+        ],
+        bad_example: "fn main() {\n→let x = 5;\n····let y = 10;\n}",
+        good_example: "fn main() {\n····let x = 5;\n····let y = 10;\n}",
+    },
+    RuleInfo {
+        check: DisableCheck::MaxLines,
+        code: "max-lines",
+        summary: "File exceeds the maximum number of lines",
+        description: "Reports files with more lines than the maximum, on the first line past the \
+            limit. Very long files are often generated or accidentally committed.",
+        fixable: false,
+        parameters: &[RuleParameter {
+            flag: "--max-lines <NUM>",
+            help: "Maximum allowed lines per file (0 for no limit)",
+            default: "0",
+        }],
+        bad_example: "line 1\nline 2\nline 3 (with --max-lines 2)",
+        good_example: "line 1\nline 2",
+    },
+    RuleInfo {
+        check: DisableCheck::MaxFileSize,
+        code: "max-file-size",
+        summary: "File exceeds the maximum size",
+        description: "Reports files larger than the maximum size, such as dumps or large \
+            fixtures. The size is taken from the file metadata, and oversized files are not read \
+            or checked by any other rule.",
+        fixable: false,
+        parameters: &[RuleParameter {
+            flag: "--max-file-size <SIZE>",
+            help: "Maximum allowed file size, e.g. 512K or 50M (0 for no limit)",
+            default: "0",
+        }],
+        bad_example: "dump.sql (62914560 bytes, with --max-file-size 50M)",
+        good_example: "dump.sql (1048576 bytes)",
+    },
+    RuleInfo {
+        check: DisableCheck::LeadingBlank,
+        code: "leading-blank",
+        summary: "Blank lines at the start of a file",
+        description: "Reports blank and whitespace-only lines before the first line with \
+            content. The fix removes them.",
+        fixable: true,
+        parameters: &[],
+        bad_example: "\n\nlet x = 5;",
+        good_example: "let x = 5;",
+    },
+    RuleInfo {
+        check: DisableCheck::WhitespaceOnlyLine,
+        code: "whitespace-only-line",
+        summary: "Lines containing only whitespaces or tabs",
+        description: "Reports lines that contain only spaces and tabs, which would otherwise be \
+            reported as `trailing-space`. The fix empties them and keeps the line ending.",
+        fixable: true,
+        parameters: &[],
+        bad_example: "let x = 5;\n→··\nlet y = 10;",
+        good_example: "let x = 5;\n\nlet y = 10;",
+    },
+    RuleInfo {
+        check: DisableCheck::SpaceBeforeTab,
+        code: "space-before-tab",
+        summary: "Spaces before a tab in the indentation",
+        description: "Reports spaces followed by a tab in the indentation of a line, like the \
+            `space-before-tab` class of Git's `core.whitespace`. Only checked when the class is \
+            given with `--whitespace`, or set in the repository with `--whitespace git`. The fix \
+            converts the indentation to tabs.",
+        fixable: true,
+        parameters: &[RuleParameter {
+            flag: "--whitespace <CLASSES>",
+            help: "Git whitespace classes, or 'git' to read core.whitespace",
+            default: "blank-at-eol,cr-at-eol",
+        }],
+        bad_example: "··→let x = 5;",
+        good_example: "→let x = 5;",
+    },
+    RuleInfo {
+        check: DisableCheck::IndentWithNonTab,
+        code: "indent-with-non-tab",
+        summary: "Indentation with spaces instead of tabs",
+        description: "Reports indentation with a tab width or more of spaces instead of the \
+            equivalent tabs, like the `indent-with-non-tab` class of Git's `core.whitespace`. \
+            The tab width is the `tabwidth=<n>` class (8 with `--whitespace git`) or \
+            `--tab-width`. Only checked when the class is enabled, and the fix converts the \
+            indentation to tabs.",
+        fixable: true,
+        parameters: &[RuleParameter {
+            flag: "--whitespace <CLASSES>",
+            help: "Git whitespace classes, or 'git' to read core.whitespace",
+            default: "blank-at-eol,cr-at-eol",
+        }],
+        bad_example: "········let x = 5; (with tabwidth=8)",
+        good_example: "→let x = 5;",
+    },
+    RuleInfo {
+        check: DisableCheck::TabInIndent,
+        code: "tab-in-indent",
+        summary: "Tabs in the indentation",
+        description: "Reports tabs in the indentation of a line, like the `tab-in-indent` class \
+            of Git's `core.whitespace`, which cannot be combined with `indent-with-non-tab`. Only \
+            checked when the class is enabled, and the fix converts the indentation to spaces.",
+        fixable: true,
+        parameters: &[RuleParameter {
+            flag: "--whitespace <CLASSES>",
+            help: "Git whitespace classes, or 'git' to read core.whitespace",
+            default: "blank-at-eol,cr-at-eol",
+        }],
+        bad_example: "→let x = 5;",
+        good_example: "····let x = 5;",
+    },
+    RuleInfo {
+        check: DisableCheck::BlankAtEof,
+        code: "blank-at-eof",
+        summary: "Blank lines at the end of a file",
+        description: "Reports empty or whitespace-only lines at the end of a file, like the \
+            `blank-at-eof` class of Git's `core.whitespace`. Unlike `consecutive-blank`, a \
+            single blank line is reported. Only checked when the class is enabled, and the fix \
+            removes the lines.",
+        fixable: true,
+        parameters: &[RuleParameter {
+            flag: "--whitespace <CLASSES>",
+            help: "Git whitespace classes, or 'git' to read core.whitespace",
+            default: "blank-at-eol,cr-at-eol",
+        }],
+        bad_example: "let x = 5;\n\n",
+        good_example: "let x = 5;\n",
+    },
+    RuleInfo {
+        check: DisableCheck::MergeArtifact,
+        code: "merge-artifact",
+        summary: "Leftover merge artifact files",
+        description: "Reports files left behind by merge tools and patch that are usually \
+            committed by accident: `*.orig` backups, `*.rej` rejected hunks, and the \
+            `*.BACKUP.*`, `*.BASE.*`, `*.LOCAL.*` and `*.REMOTE.*` temporary files of git \
+            mergetool. Only the file name is checked; the contents are still linted.",
+        fixable: false,
+        parameters: &[],
+        bad_example: "src/main.rs.orig",
+        good_example: "src/main.rs",
+    },
+    RuleInfo {
+        check: DisableCheck::CaseCollision,
+        code: "case-collision",
+        summary: "Paths differing only in case",
+        description: "Reports input paths, including their directories, that differ from another \
+            input path only in letter case. Only one of them survives a checkout on \
+            case-insensitive file systems such as the defaults of Windows and macOS. Both paths \
+            are listed in the diagnostic.",
+        fixable: false,
+        parameters: &[],
+        bad_example: "README.md\nreadme.md",
+        good_example: "README.md",
+    },
+    RuleInfo {
+        check: DisableCheck::PortablePath,
+        code: "portable-path",
+        summary: "Path not portable across platforms",
+        description: "Reports path components that cannot be checked out on every platform: \
+            characters not allowed on Windows (< > : \" | ? * and control characters), a \
+            trailing dot or space, reserved Windows device names (CON, PRN, AUX, NUL, COM1 to \
+            COM9 and LPT1 to LPT9, with any extension), and names that are not in Unicode \
+            normalization form C, which macOS may normalize differently.",
+        fixable: false,
+        parameters: &[],
+        bad_example: "docs/what?.md\nnul.txt",
+        good_example: "docs/what.md\nnull.txt",
+    },
+    RuleInfo {
+        check: DisableCheck::PathLength,
+        code: "path-length",
+        summary: "Path exceeds the maximum length",
+        description: "Reports input paths longer than the maximum number of characters. Windows \
+            limits full paths to 260 characters by default, including the directory of the \
+            checkout.",
+        fixable: false,
+        parameters: &[RuleParameter {
+            flag: "--max-path-length <NUM>",
+            help: "Maximum allowed path length in characters (0 for no limit)",
+            default: "260",
+        }],
+        bad_example: "src/a_very_long_directory_name/.../file.rs (300 characters)",
+        good_example: "src/module/file.rs",
+    },
+    RuleInfo {
+        check: DisableCheck::Secret,
+        code: "secret",
+        summary: "Committed secrets and credentials",
+        description: "Reports private key headers, AWS access key IDs, GitHub, GitLab and Slack \
+            tokens, and random-looking values assigned to keys such as `password`, `secret`, \
+            `token` or `api_key`. Secrets are masked in every diagnostic of the file so that the \
+            report does not leak them. Values matching a glob pattern of `secret-allowlist` in \
+            the configuration file, such as documented example keys, are ignored.",
+        fixable: false,
+        parameters: &[],
+        bad_example: "aws_access_key_id = \"AKIA****************\"",
+        good_example: "aws_access_key_id = os.environ[\"AWS_ACCESS_KEY_ID\"]",
+    },
+    RuleInfo {
+        check: DisableCheck::Todo,
+        code: "todo",
+        summary: "Technical-debt markers",
+        description: "Reports technical-debt keywords such as TODO and FIXME written as whole \
+            words in any language. The message holds the keyword, its reference in parentheses \
+            and the text after it, so that `--format jsonl` output can be collected into a debt \
+            report. With `--todo-issue-pattern`, markers without a reference matching the glob \
+            pattern, such as TODO(#123) or TODO(PROJ-456), are reported as such.",
+        fixable: false,
+        parameters: &[
+            RuleParameter {
+                flag: "--todo-keywords <KEYWORDS>",
+                help: "Technical-debt keywords",
+                default: "TODO,FIXME,XXX,HACK",
+            },
+            RuleParameter {
+                flag: "--todo-issue-pattern <PATTERN>",
+                help: "Glob pattern of the required issue reference, e.g. '#[0-9]*'",
+                default: "none",
+            },
+        ],
+        bad_example: "// TODO: handle errors (with --todo-issue-pattern '#[0-9]*')",
+        good_example: "// TODO(#123): handle errors",
+    },
+    RuleInfo {
+        check: DisableCheck::Header,
+        code: "header",
+        summary: "Missing license header",
+        description: "Reports files whose first lines (`header-lines` in the configuration file, \
+            10 by default) do not contain the lines of the `header` template in order, such as \
+            an SPDX identifier and a copyright line. Each template line has to make up the \
+            whole line apart from surrounding comment syntax, and is compared literally except \
+            for `{year}`, which matches a year like `2024` or a range like `2019-2024`; regular \
+            expressions are not supported. Templates and comment syntax can be set per glob pattern with \
+            `[[overrides]]`, and an empty template exempts files. When the whole header is \
+            missing and `header-comment` is set, the fix inserts it with the current year, after \
+            a shebang if there is one.",
+        fixable: true,
+        parameters: &[],
+        bad_example: "fn main() {}",
+        good_example: "// SPDX-License-Identifier: MIT\n// Copyright (c) 2024 Acme Inc.\n\nfn main() {}",
+    },
+    RuleInfo {
+        check: DisableCheck::Shebang,
+        code: "shebang",
+        summary: "Shebang and executable bit mismatch",
+        description: "Reports scripts starting with a shebang (#!) that are not executable and \
+            executable files without one, using the Unix permission bits of the file. Shebangs \
+            are also reported when the kernel cannot run them: an interpreter that is not an \
+            absolute path, /usr/bin/env without a program, a CRLF line ending that becomes part \
+            of the interpreter name, or a byte order mark before it. Rust inner attributes such \
+            as #![allow(...)] are not shebangs.",
+        fixable: false,
+        parameters: &[],
+        bad_example: "#! python (not executable)",
+        good_example: "#!/usr/bin/env python3 (executable)",
+    },
+];
+
+pub fn rule_info(check: DisableCheck) -> &'static RuleInfo {
+    RULES
+        .iter()
+        .find(|rule| rule.check == check)
+        .expect("every check has rule metadata")
+}
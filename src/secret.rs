@@ -0,0 +1,185 @@
+// Credential patterns of the secret rule, matched by hand like the other character classes
+
+pub const PRIVATE_KEY: &str = "Private key";
+
+pub struct Secret {
+    pub kind: &'static str,
+    // Byte range of the secret in the line
+    pub start: usize,
+    pub end: usize,
+}
+
+// Tokens recognized by their prefix: (prefix, kind, minimum and maximum length after the prefix)
+const TOKEN_PREFIXES: &[(&str, &str, usize, usize)] = &[
+    ("AKIA", "AWS access key ID", 16, 16),
+    ("ASIA", "AWS access key ID", 16, 16),
+    ("ghp_", "GitHub token", 36, 36),
+    ("gho_", "GitHub token", 36, 36),
+    ("ghu_", "GitHub token", 36, 36),
+    ("ghs_", "GitHub token", 36, 36),
+    ("ghr_", "GitHub token", 36, 36),
+    ("github_pat_", "GitHub token", 22, 255),
+    ("glpat-", "GitLab token", 20, 255),
+    ("xoxa-", "Slack token", 10, 255),
+    ("xoxb-", "Slack token", 10, 255),
+    ("xoxp-", "Slack token", 10, 255),
+    ("xoxr-", "Slack token", 10, 255),
+    ("xoxs-", "Slack token", 10, 255),
+];
+
+// Assignments to keys containing these words are checked for random-looking values
+const SECRET_KEYWORDS: &[&str] = &[
+    "password",
+    "passwd",
+    "secret",
+    "token",
+    "api_key",
+    "apikey",
+    "api-key",
+    "access_key",
+    "private_key",
+];
+
+const MIN_GENERIC_LENGTH: usize = 16;
+const MIN_GENERIC_ENTROPY: f64 = 3.5;
+
+fn is_token_char(prefix: &str, b: u8) -> bool {
+    match prefix {
+        "AKIA" | "ASIA" => b.is_ascii_uppercase() || b.is_ascii_digit(),
+        "github_pat_" => b.is_ascii_alphanumeric() || b == b'_',
+        "glpat-" => b.is_ascii_alphanumeric() || b == b'_' || b == b'-',
+        _ if prefix.starts_with("xox") => b.is_ascii_alphanumeric() || b == b'-',
+        _ => b.is_ascii_alphanumeric(),
+    }
+}
+
+fn is_value_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'=' | b'_' | b'.' | b'~' | b'-')
+}
+
+// Shannon entropy in bits per character
+fn entropy(value: &str) -> f64 {
+    let mut counts = [0usize; 256];
+    for &b in value.as_bytes() {
+        counts[b as usize] += 1;
+    }
+    let len = value.len() as f64;
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+fn find_private_key(line: &str) -> Option<Secret> {
+    let start = line.find("-----BEGIN ")?;
+    let header_len = line[start + 11..].find("-----")? + 16;
+    let header = &line[start..start + header_len];
+    (header.contains("PRIVATE KEY")).then_some(Secret {
+        kind: PRIVATE_KEY,
+        start,
+        end: start + header_len,
+    })
+}
+
+fn find_tokens(line: &str, secrets: &mut Vec<Secret>) {
+    let bytes = line.as_bytes();
+    for &(prefix, kind, min_len, max_len) in TOKEN_PREFIXES {
+        for (start, _) in line.match_indices(prefix) {
+            if start > 0 && (bytes[start - 1].is_ascii_alphanumeric() || bytes[start - 1] == b'_') {
+                continue;
+            }
+            let body = start + prefix.len();
+            let len = bytes[body..]
+                .iter()
+                .take_while(|&&b| is_token_char(prefix, b))
+                .count();
+            if (min_len..=max_len).contains(&len) {
+                secrets.push(Secret {
+                    kind,
+                    start,
+                    end: body + len,
+                });
+            }
+        }
+    }
+}
+
+fn find_assignments(line: &str, secrets: &mut Vec<Secret>) {
+    let bytes = line.as_bytes();
+    let lower = line.to_ascii_lowercase();
+    for keyword in SECRET_KEYWORDS {
+        for (key_start, _) in lower.match_indices(keyword) {
+            // Skip the rest of the key, its closing quote and the assignment operator
+            let mut pos = key_start + keyword.len();
+            while pos < bytes.len()
+                && (bytes[pos].is_ascii_alphanumeric() || matches!(bytes[pos], b'_' | b'.' | b'-'))
+            {
+                pos += 1;
+            }
+            if pos < bytes.len() && matches!(bytes[pos], b'"' | b'\'') {
+                pos += 1;
+            }
+            while pos < bytes.len() && bytes[pos] == b' ' {
+                pos += 1;
+            }
+            if pos >= bytes.len() || !matches!(bytes[pos], b'=' | b':') {
+                continue;
+            }
+            pos += 1;
+            if pos < bytes.len() && matches!(bytes[pos], b'=' | b'>') {
+                pos += 1;
+            }
+            while pos < bytes.len() && bytes[pos] == b' ' {
+                pos += 1;
+            }
+            if pos < bytes.len() && matches!(bytes[pos], b'"' | b'\'' | b'`') {
+                pos += 1;
+            }
+
+            let start = pos;
+            while pos < bytes.len() && is_value_char(bytes[pos]) {
+                pos += 1;
+            }
+            // Calls and variable references are not literal values
+            let terminated = pos == bytes.len()
+                || matches!(
+                    bytes[pos],
+                    b'"' | b'\'' | b'`' | b' ' | b'\t' | b'\r' | b'\n' | b',' | b';'
+                );
+            let value = &line[start..pos];
+            if terminated
+                && value.len() >= MIN_GENERIC_LENGTH
+                && entropy(value) >= MIN_GENERIC_ENTROPY
+                && !secrets.iter().any(|s| s.start < pos && start < s.end)
+            {
+                secrets.push(Secret {
+                    kind: "High-entropy secret",
+                    start,
+                    end: pos,
+                });
+            }
+        }
+    }
+}
+
+// Secrets in a line, ordered by position
+pub fn find_secrets(line: &str) -> Vec<Secret> {
+    let mut secrets = Vec::new();
+    if let Some(secret) = find_private_key(line) {
+        secrets.push(secret);
+    }
+    find_tokens(line, &mut secrets);
+    find_assignments(line, &mut secrets);
+    secrets.sort_by_key(|secret| secret.start);
+    secrets
+}
+
+// Keeps a short prefix of long secrets and the length of all of them
+pub fn mask_secret(value: &str) -> String {
+    let visible = if value.len() > 12 { 4 } else { 0 };
+    format!("{}{}", &value[..visible], "*".repeat(value.len() - visible))
+}
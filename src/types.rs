@@ -1,4 +1,7 @@
-use crate::enums::{ConflictMarkerStyle, DisableCheck};
+use crate::enums::{
+    BomPolicy, ConflictMarkerStyle, DisableCheck, EncodingPolicy, EolStyle, FinalNewlinePolicy,
+    IndentStyle, NormalizationForm, Severity,
+};
 use serde::Serialize;
 use serde_partial::SerializePartial;
 use std::collections::{HashMap, HashSet};
@@ -36,16 +39,130 @@ struct DiagnosticStats {
     limit_reached: bool,
 }
 
-#[derive(Debug)]
+// Classes of Git's `core.whitespace` deciding which whitespace rules run
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WhitespaceClasses {
+    pub blank_at_eol: bool,
+    pub space_before_tab: bool,
+    pub indent_with_non_tab: bool,
+    pub tab_in_indent: bool,
+    pub blank_at_eof: bool,
+    // A carriage return before a line feed is part of the line ending, not trailing whitespace
+    pub cr_at_eol: bool,
+    // Width of a tab for indent-with-non-tab, --tab-width if not given
+    pub tab_width: Option<usize>,
+}
+
+impl WhitespaceClasses {
+    // Classes checked by genlint, which explicit `--whitespace` classes are applied on top of
+    pub const DEFAULT: Self = Self {
+        blank_at_eol: true,
+        space_before_tab: false,
+        indent_with_non_tab: false,
+        tab_in_indent: false,
+        blank_at_eof: false,
+        cr_at_eol: true,
+        tab_width: None,
+    };
+
+    // Classes enabled by Git when `core.whitespace` does not turn them off
+    pub const GIT_DEFAULT: Self = Self {
+        blank_at_eol: true,
+        space_before_tab: true,
+        indent_with_non_tab: false,
+        tab_in_indent: false,
+        blank_at_eof: true,
+        cr_at_eol: false,
+        tab_width: Some(8),
+    };
+
+    // Apply a comma-separated list of classes like `core.whitespace`, where a leading `-` turns a
+    // class off
+    pub fn parse(value: &str, base: Self) -> Result<Self, String> {
+        let mut classes = base;
+        for item in value
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            let (name, enabled) = match item.strip_prefix('-') {
+                Some(name) => (name, false),
+                None => (item, true),
+            };
+            match name {
+                "blank-at-eol" => classes.blank_at_eol = enabled,
+                "space-before-tab" => classes.space_before_tab = enabled,
+                "indent-with-non-tab" => classes.indent_with_non_tab = enabled,
+                "tab-in-indent" => classes.tab_in_indent = enabled,
+                "blank-at-eof" => classes.blank_at_eof = enabled,
+                "cr-at-eol" => classes.cr_at_eol = enabled,
+                "trailing-space" => {
+                    classes.blank_at_eol = enabled;
+                    classes.blank_at_eof = enabled;
+                }
+                _ => match name.strip_prefix("tabwidth=").map(str::parse::<usize>) {
+                    Some(Ok(width)) if enabled && (1..64).contains(&width) => {
+                        classes.tab_width = Some(width)
+                    }
+                    _ => return Err(format!("invalid whitespace class '{}'", item)),
+                },
+            }
+        }
+        if classes.indent_with_non_tab && classes.tab_in_indent {
+            return Err("cannot enforce both tab-in-indent and indent-with-non-tab".to_string());
+        }
+        Ok(classes)
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct LintOptions {
     pub disables: Vec<DisableCheck>,
     pub line_length: usize,
+    pub tab_width: usize,
     pub consecutive_blank: usize,
+    pub max_lines: usize,
+    pub max_file_size: u64,
+    pub max_path_length: usize,
     pub max_errors: usize,
     pub max_warnings: usize,
     pub max_info: usize,
     pub text_mode: bool,
     pub conflict_marker_style: ConflictMarkerStyle,
+    pub strict_conflict_markers: bool,
+    pub eol_style: EolStyle,
+    pub final_newline: FinalNewlinePolicy,
+    pub encoding: EncodingPolicy,
+    pub bom: BomPolicy,
+    pub normalization_form: NormalizationForm,
+    pub indent_style: IndentStyle,
+    pub indent_width: usize,
+    pub whitespace: WhitespaceClasses,
+    // Glob patterns of values that the secret rule ignores
+    pub secret_allowlist: Vec<glob::Pattern>,
+    pub todo_keywords: Vec<String>,
+    // Glob pattern that the reference in parentheses after a keyword must match, if required
+    pub todo_issue_pattern: Option<glob::Pattern>,
+    // Template lines of the required header, where {year} stands for a year or a range of years
+    pub header: Vec<String>,
+    pub header_comment: Option<String>,
+    pub header_lines: usize,
+    // Control characters that the control-char rule ignores, such as form feeds
+    pub allowed_control_chars: Vec<char>,
+    // Unix executable bit of the file being linted, if known
+    pub executable: Option<bool>,
+    pub severities: Vec<(DisableCheck, Severity)>,
+}
+
+impl LintOptions {
+    pub fn severity(&self, check: DisableCheck) -> &'static str {
+        self.severities
+            .iter()
+            .rev()
+            .find(|(c, _)| *c == check)
+            .map_or(check.default_severity(), |(_, level)| *level)
+            .as_str()
+    }
 }
 
 #[derive(Debug, Default)]
@@ -86,6 +203,13 @@ impl LintRunner {
         self.limited_stats[diag_type].limit_reached
     }
 
+    // How many more diagnostics of a severity can be reported, or None without a limit
+    pub fn remaining_issues(&self, opts: &LintOptions, severity: &str) -> Option<usize> {
+        let diag_type = DiagnosticType::from_severity(severity)?;
+        let max_limit = diag_type.max_limit(opts);
+        (max_limit > 0).then(|| max_limit.saturating_sub(self.limited_stats[&diag_type].count))
+    }
+
     pub fn can_add_issue(&self, severity: &str) -> bool {
         if let Some(diag_type) = DiagnosticType::from_severity(severity) {
             !self.processing_blocked.contains(&diag_type)
@@ -94,14 +218,20 @@ impl LintRunner {
         }
     }
 
-    pub fn clear_conflict_markers(&mut self) {
-        self.diagnostics.retain(|d| d.code != "conflict-marker");
-        if let Some(stats) = self.limited_stats.get_mut(&DiagnosticType::Error) {
-            stats.count = 0;
-            stats.limit_reached = false;
-            stats.has_printed_limit = false;
+    // Drop the conflict markers of one file, reported before its marker length was known
+    pub fn clear_conflict_markers(&mut self, filename: &str, severity: &str) {
+        let before = self.diagnostics.len();
+        self.diagnostics
+            .retain(|d| d.code != "conflict-marker" || d.file != filename);
+        let removed = before - self.diagnostics.len();
+        if let Some(diag_type) = DiagnosticType::from_severity(severity) {
+            if let Some(stats) = self.limited_stats.get_mut(&diag_type) {
+                stats.count = stats.count.saturating_sub(removed);
+                stats.limit_reached = false;
+                stats.has_printed_limit = false;
+            }
+            self.processing_blocked.remove(&diag_type);
         }
-        self.processing_blocked.remove(&DiagnosticType::Error);
         self.should_terminate = false;
     }
 
@@ -177,13 +307,25 @@ impl LintRunner {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Helper {
     pub message: String,
     pub lnum: usize,
     pub end_lnum: usize,
     pub col: usize,
     pub end_col: usize,
+    // Source starting at `lnum` for helpers outside of the diagnostic's source
+    pub source: Option<String>,
+}
+
+// Replaces the lines in `lnum..end_lnum` with `replacement`; an empty range inserts before `lnum`.
+// Lines never include a leading UTF-8 byte order mark, which is only inserted or removed by `bom`.
+#[derive(Clone, Debug, Serialize)]
+pub struct Fix {
+    pub lnum: usize,
+    pub end_lnum: usize,
+    pub replacement: String,
+    pub bom: Option<bool>,
 }
 
 #[derive(Debug, Serialize, SerializePartial)]
@@ -199,4 +341,5 @@ pub struct Diagnostic {
     pub code: String,
     pub message: String,
     pub helpers: Option<Vec<Helper>>,
+    pub fixes: Option<Vec<Fix>>,
 }
@@ -1,24 +1,19 @@
-use std::sync::LazyLock;
-
 use annotate_snippets::{Annotation, AnnotationKind, Level};
+use std::time::{SystemTime, UNIX_EPOCH};
 use unicode_width::UnicodeWidthChar;
 
-static ASCII_WIDTH: LazyLock<[u8; 128]> = LazyLock::new(|| {
-    let mut arr = [1u8; 128];
-    arr['\t' as usize] = 4;
-    arr
-});
+// Width of a character at a visual column; a tab advances to the next tab stop
+fn char_width(c: char, column: usize, tab_width: usize) -> usize {
+    match c {
+        '\t' => tab_width - column % tab_width,
+        c if c.is_ascii() => 1,
+        c => UnicodeWidthChar::width(c).unwrap_or(0),
+    }
+}
 
-pub fn calculate_width(s: &str) -> usize {
+pub fn calculate_width(s: &str, tab_width: usize) -> usize {
     s.chars()
-        .map(|c| {
-            if c.is_ascii() {
-                ASCII_WIDTH[c as usize] as usize
-            } else {
-                UnicodeWidthChar::width(c).unwrap_or(0)
-            }
-        })
-        .sum()
+        .fold(0, |width, c| width + char_width(c, width, tab_width))
 }
 
 pub fn coord_to_pos(source: &str, source_lnum: usize, lnum: usize, col: usize) -> usize {
@@ -73,18 +68,12 @@ pub fn severity_to_level(severity: &str) -> Level<'_> {
     }
 }
 
-pub fn char_col_at_visual_width(line: &str, width: usize) -> usize {
+pub fn char_col_at_visual_width(line: &str, width: usize, tab_width: usize) -> usize {
     let mut visual_width = 0;
     let mut char_count = 0;
 
     for ch in line.chars() {
-        let ch_width = if ch.is_ascii() {
-            ASCII_WIDTH[ch as usize] as usize
-        } else {
-            UnicodeWidthChar::width(ch).unwrap_or(0)
-        };
-
-        visual_width += ch_width;
+        visual_width += char_width(ch, visual_width, tab_width);
 
         if visual_width > width {
             break;
@@ -104,3 +93,156 @@ pub fn char_index_to_byte_range(s: &str, char_index: usize) -> (usize, usize) {
         (s.len(), s.len())
     }
 }
+
+pub fn bidi_control_name(c: char) -> Option<&'static str> {
+    let name = match c {
+        '\u{202A}' => "left-to-right embedding",
+        '\u{202B}' => "right-to-left embedding",
+        '\u{202C}' => "pop directional formatting",
+        '\u{202D}' => "left-to-right override",
+        '\u{202E}' => "right-to-left override",
+        '\u{2066}' => "left-to-right isolate",
+        '\u{2067}' => "right-to-left isolate",
+        '\u{2068}' => "first strong isolate",
+        '\u{2069}' => "pop directional isolate",
+        _ => return None,
+    };
+    Some(name)
+}
+
+pub fn invisible_char_name(c: char) -> Option<&'static str> {
+    let name = match c {
+        '\u{00A0}' => "no-break space",
+        '\u{00AD}' => "soft hyphen",
+        '\u{034F}' => "combining grapheme joiner",
+        '\u{061C}' => "arabic letter mark",
+        '\u{115F}' | '\u{1160}' | '\u{3164}' | '\u{FFA0}' => "hangul filler",
+        '\u{180E}' => "mongolian vowel separator",
+        '\u{2007}' => "figure space",
+        '\u{200B}' => "zero width space",
+        '\u{200C}' => "zero width non-joiner",
+        '\u{200D}' => "zero width joiner",
+        '\u{200E}' => "left-to-right mark",
+        '\u{200F}' => "right-to-left mark",
+        '\u{202F}' => "narrow no-break space",
+        '\u{2060}' => "word joiner",
+        '\u{2061}'..='\u{2064}' => "invisible operator",
+        '\u{206A}'..='\u{206F}' => "deprecated format character",
+        '\u{FEFF}' => "zero width no-break space",
+        '\u{FFF9}'..='\u{FFFB}' => "interlinear annotation character",
+        _ => return None,
+    };
+    Some(name)
+}
+
+// ASCII character that a character is easily mistaken for, with a description of the character
+pub fn confusable_char(c: char) -> Option<(&'static str, char)> {
+    let confusable = match c {
+        '\u{2018}' => ("left single quotation mark", '\''),
+        '\u{2019}' => ("right single quotation mark", '\''),
+        '\u{201A}' => ("single low-9 quotation mark", ','),
+        '\u{201B}' => ("single high-reversed-9 quotation mark", '\''),
+        '\u{201C}' => ("left double quotation mark", '"'),
+        '\u{201D}' => ("right double quotation mark", '"'),
+        '\u{201E}' => ("double low-9 quotation mark", '"'),
+        '\u{201F}' => ("double high-reversed-9 quotation mark", '"'),
+        '\u{2032}' => ("prime", '\''),
+        '\u{2033}' => ("double prime", '"'),
+        '\u{00B4}' => ("acute accent", '\''),
+        '\u{2010}' => ("hyphen", '-'),
+        '\u{2011}' => ("non-breaking hyphen", '-'),
+        '\u{2012}' => ("figure dash", '-'),
+        '\u{2013}' => ("en dash", '-'),
+        '\u{2014}' => ("em dash", '-'),
+        '\u{2015}' => ("horizontal bar", '-'),
+        '\u{2212}' => ("minus sign", '-'),
+        '\u{FE63}' => ("small hyphen-minus", '-'),
+        '\u{2024}' => ("one dot leader", '.'),
+        '\u{2044}' => ("fraction slash", '/'),
+        '\u{2215}' => ("division slash", '/'),
+        '\u{2236}' => ("ratio", ':'),
+        '\u{037E}' => ("greek question mark", ';'),
+        '\u{3000}' => ("ideographic space", ' '),
+        '\u{FF01}'..='\u{FF5E}' => (
+            "fullwidth form",
+            char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        ),
+        '\u{0430}' => ("cyrillic letter", 'a'),
+        '\u{0435}' => ("cyrillic letter", 'e'),
+        '\u{043E}' => ("cyrillic letter", 'o'),
+        '\u{0440}' => ("cyrillic letter", 'p'),
+        '\u{0441}' => ("cyrillic letter", 'c'),
+        '\u{0443}' => ("cyrillic letter", 'y'),
+        '\u{0445}' => ("cyrillic letter", 'x'),
+        '\u{0455}' => ("cyrillic letter", 's'),
+        '\u{0456}' => ("cyrillic letter", 'i'),
+        '\u{0458}' => ("cyrillic letter", 'j'),
+        '\u{0405}' => ("cyrillic letter", 'S'),
+        '\u{0406}' => ("cyrillic letter", 'I'),
+        '\u{0408}' => ("cyrillic letter", 'J'),
+        '\u{0410}' => ("cyrillic letter", 'A'),
+        '\u{0412}' => ("cyrillic letter", 'B'),
+        '\u{0415}' => ("cyrillic letter", 'E'),
+        '\u{041A}' => ("cyrillic letter", 'K'),
+        '\u{041C}' => ("cyrillic letter", 'M'),
+        '\u{041D}' => ("cyrillic letter", 'H'),
+        '\u{041E}' => ("cyrillic letter", 'O'),
+        '\u{0420}' => ("cyrillic letter", 'P'),
+        '\u{0421}' => ("cyrillic letter", 'C'),
+        '\u{0422}' => ("cyrillic letter", 'T'),
+        '\u{0425}' => ("cyrillic letter", 'X'),
+        '\u{03BF}' => ("greek letter", 'o'),
+        '\u{03BD}' => ("greek letter", 'v'),
+        '\u{0391}' => ("greek letter", 'A'),
+        '\u{0392}' => ("greek letter", 'B'),
+        '\u{0395}' => ("greek letter", 'E'),
+        '\u{0396}' => ("greek letter", 'Z'),
+        '\u{0397}' => ("greek letter", 'H'),
+        '\u{0399}' => ("greek letter", 'I'),
+        '\u{039A}' => ("greek letter", 'K'),
+        '\u{039C}' => ("greek letter", 'M'),
+        '\u{039D}' => ("greek letter", 'N'),
+        '\u{039F}' => ("greek letter", 'O'),
+        '\u{03A1}' => ("greek letter", 'P'),
+        '\u{03A4}' => ("greek letter", 'T'),
+        '\u{03A5}' => ("greek letter", 'Y'),
+        '\u{03A7}' => ("greek letter", 'X'),
+        _ => return None,
+    };
+    Some(confusable)
+}
+
+// C0 and C1 control characters other than tab and line endings
+pub fn control_char_name(c: char) -> Option<&'static str> {
+    let name = match c {
+        '\t' | '\n' | '\r' => return None,
+        '\0' => "null",
+        '\u{07}' => "bell",
+        '\u{08}' => "backspace",
+        '\u{0B}' => "vertical tab",
+        '\u{0C}' => "form feed",
+        '\u{1B}' => "escape",
+        '\u{7F}' => "delete",
+        '\u{85}' => "next line",
+        '\u{00}'..='\u{1F}' => "control character",
+        '\u{80}'..='\u{9F}' => "C1 control character",
+        _ => return None,
+    };
+    Some(name)
+}
+
+// Year of the current UTC date, converted from days since the Unix epoch
+pub fn current_year() -> i64 {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / 86400) as i64;
+    // Eras of 400 years starting on March 1st, so that leap days end a year
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    era * 400 + year_of_era + i64::from(month >= 10)
+}
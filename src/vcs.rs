@@ -0,0 +1,68 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::enums::ConflictMarkerStyle;
+
+// Choose the conflict marker style from the repository containing `dir`. Jujutsu takes
+// precedence since it writes the conflicts of repositories colocated with Git.
+pub fn detect_conflict_marker_style(dir: &Path) -> ConflictMarkerStyle {
+    for dir in dir.ancestors() {
+        let jj_dir = dir.join(".jj");
+        if jj_dir.is_dir() {
+            return jj_conflict_marker_style(&jj_dir.join("repo").join("config.toml"));
+        }
+        // Worktrees and submodules have a `.git` file pointing at the Git directory
+        if dir.join(".git").exists() {
+            return git_conflict_marker_style(dir);
+        }
+    }
+    ConflictMarkerStyle::Git
+}
+
+// `ui.conflict-marker-style` of the repository configuration, defaulting to `diff`
+fn jj_conflict_marker_style(config: &Path) -> ConflictMarkerStyle {
+    let style = fs::read_to_string(config)
+        .ok()
+        .and_then(|content| content.parse::<toml::Table>().ok())
+        .and_then(|table| {
+            table
+                .get("ui")?
+                .get("conflict-marker-style")?
+                .as_str()
+                .map(str::to_string)
+        });
+    match style.as_deref() {
+        Some("snapshot") => ConflictMarkerStyle::JjSnapshot,
+        Some("git") => ConflictMarkerStyle::JjDiff3,
+        _ => ConflictMarkerStyle::Jj,
+    }
+}
+
+// `merge.conflictStyle` of the Git configuration, defaulting to `merge`
+fn git_conflict_marker_style(dir: &Path) -> ConflictMarkerStyle {
+    // Both diff3 and zdiff3 add the common ancestor section
+    match git_config_value(dir, "merge.conflictStyle").as_deref() {
+        Some("diff3" | "zdiff3") => ConflictMarkerStyle::GitDiff3,
+        _ => ConflictMarkerStyle::Git,
+    }
+}
+
+// `core.whitespace` of the Git configuration seen from `dir`
+pub fn git_whitespace(dir: &Path) -> Option<String> {
+    git_config_value(dir, "core.whitespace")
+}
+
+// Ask Git itself so that the repository, global, system and included configuration files are
+// all taken into account. Without Git installed, the key is treated as unset.
+fn git_config_value(dir: &Path, key: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["config", "--get", key])
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}